@@ -1,4 +1,5 @@
 use rand::distributions::{Distribution, Uniform};
+use rand::Rng;
 use std::fmt::Debug;
 use std::ops;
 use std::{
@@ -10,27 +11,24 @@ use std::{
 pub struct CardSet(u64);
 
 impl CardSet {
-    #[allow(dead_code)]
     pub fn none() -> Self {
         Self(0)
     }
-    #[allow(dead_code)]
     pub fn all() -> Self {
         Self(0b1111111111111111111111111111111111111111111111111111)
     }
 
-    #[allow(dead_code)]
     pub fn one(index: u32) -> Self {
         assert!(index < 52);
         Self(1 << index)
     }
 
-    pub fn draw(&mut self, mut count: u32) -> CardSet {
+    /// Removes `count` random cards, or all of them if there are fewer, and returns them
+    pub fn draw<R: Rng + ?Sized>(&mut self, mut count: u32, rng: &mut R) -> CardSet {
         let mut cards = self.0;
-        let mut rng = rand::thread_rng();
         let die = Uniform::from(0..52);
         while count > 0 && cards != 0 {
-            let card_index = die.sample(&mut rng);
+            let card_index = die.sample(rng);
             let after = cards & !(1 << card_index);
             if after != cards {
                 cards = after;
@@ -52,6 +50,51 @@ impl CardSet {
     pub fn as_u64(&self) -> u64 {
         self.0
     }
+
+    /// Iterates over every subset of exactly `count` cards
+    pub fn subsets(&self, count: u32) -> Subsets {
+        let cards: Vec<u64> = (0..52)
+            .filter(|i| self.0 & (1 << i) != 0)
+            .map(|i| 1 << i)
+            .collect();
+        let count = count as usize;
+        Subsets {
+            done: count > cards.len(),
+            indices: (0..count).collect(),
+            cards,
+        }
+    }
+}
+
+/// Iterator over the subsets of a `CardSet`, see `CardSet::subsets`
+pub struct Subsets {
+    cards: Vec<u64>,
+    indices: Vec<usize>,
+    done: bool,
+}
+
+impl Iterator for Subsets {
+    type Item = CardSet;
+
+    fn next(&mut self) -> Option<CardSet> {
+        if self.done {
+            return None;
+        }
+        let subset = self.indices.iter().map(|&i| self.cards[i]).sum();
+        // advance the rightmost index that still has room, resetting the ones after it
+        let n = self.cards.len();
+        let k = self.indices.len();
+        match (0..k).rev().find(|&i| self.indices[i] < n - k + i) {
+            Some(i) => {
+                self.indices[i] += 1;
+                for j in i + 1..k {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+        Some(CardSet(subset))
+    }
 }
 
 impl ops::Not for CardSet {
@@ -92,6 +135,7 @@ pub enum CardParseError {
     UnexpectedEndOfInput,
 }
 
+#[allow(clippy::manual_range_contains, clippy::erasing_op, clippy::identity_op)]
 fn parse_one_card(chars: &mut Peekable<Chars>) -> Result<Option<CardSet>, CardParseError> {
     let maybe_num_char_1 = chars.next().map(|c| c.to_ascii_uppercase());
     if maybe_num_char_1.is_none() {
@@ -158,6 +202,7 @@ impl FromStr for CardSet {
 }
 
 impl Debug for CardSet {
+    #[allow(clippy::char_lit_as_u8)]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut is_first = true;
         for number in 0..13 {
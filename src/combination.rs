@@ -0,0 +1,672 @@
+use super::cardset::CardSet;
+use std::sync::OnceLock;
+#[derive(Eq, PartialEq, PartialOrd, Ord, Clone, Copy)]
+pub struct Combination(u32);
+
+#[derive(Eq, PartialEq, Clone, Debug, Copy)]
+#[repr(u32)]
+pub enum CombinationType {
+    RoyalFlush = (1 << 31) + 12,
+    StraightFlush = 1 << 31,
+    Poker = 1 << 30,
+    FullHouse = 1 << 29,
+    Flush = 1 << 28,
+    Straight = 1 << 27,
+    Tris = 1 << 26,
+    TwoPairs = 1 << 25,
+    Pair = 1 << 24,
+    HighCard = 1 << 23,
+}
+
+impl CombinationType {
+    /// All the categories, from the weakest to the strongest
+    pub const ALL: [CombinationType; 10] = [
+        CombinationType::HighCard,
+        CombinationType::Pair,
+        CombinationType::TwoPairs,
+        CombinationType::Tris,
+        CombinationType::Straight,
+        CombinationType::Flush,
+        CombinationType::FullHouse,
+        CombinationType::Poker,
+        CombinationType::StraightFlush,
+        CombinationType::RoyalFlush,
+    ];
+    /// Position of the category in `CombinationType::ALL`
+    pub fn index(self) -> usize {
+        match self {
+            CombinationType::HighCard => 0,
+            CombinationType::Pair => 1,
+            CombinationType::TwoPairs => 2,
+            CombinationType::Tris => 3,
+            CombinationType::Straight => 4,
+            CombinationType::Flush => 5,
+            CombinationType::FullHouse => 6,
+            CombinationType::Poker => 7,
+            CombinationType::StraightFlush => 8,
+            CombinationType::RoyalFlush => 9,
+        }
+    }
+}
+
+/// Combination decoded into its category and the ranks of its cards, from 0 for deuces
+/// to 12 for aces, highest first. Kickers missing from hands of fewer than five cards
+/// are absent
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum CombinationRanks {
+    HighCard {
+        ranks: Vec<u32>,
+    },
+    Pair {
+        pair: u32,
+        kickers: Vec<u32>,
+    },
+    TwoPairs {
+        high: u32,
+        low: u32,
+        kicker: Option<u32>,
+    },
+    Tris {
+        tris: u32,
+        kickers: Vec<u32>,
+    },
+    /// `high` is the rank of the highest card, 3 for A-2-3-4-5
+    Straight {
+        high: u32,
+    },
+    Flush {
+        ranks: Vec<u32>,
+    },
+    FullHouse {
+        tris: u32,
+        pair: u32,
+    },
+    Poker {
+        poker: u32,
+        kicker: Option<u32>,
+    },
+    /// `high` below 12, the straight flush to the ace being a royal flush
+    StraightFlush {
+        high: u32,
+    },
+    RoyalFlush,
+}
+
+impl CombinationRanks {
+    pub fn category(&self) -> CombinationType {
+        match self {
+            CombinationRanks::HighCard { .. } => CombinationType::HighCard,
+            CombinationRanks::Pair { .. } => CombinationType::Pair,
+            CombinationRanks::TwoPairs { .. } => CombinationType::TwoPairs,
+            CombinationRanks::Tris { .. } => CombinationType::Tris,
+            CombinationRanks::Straight { .. } => CombinationType::Straight,
+            CombinationRanks::Flush { .. } => CombinationType::Flush,
+            CombinationRanks::FullHouse { .. } => CombinationType::FullHouse,
+            CombinationRanks::Poker { .. } => CombinationType::Poker,
+            CombinationRanks::StraightFlush { .. } => CombinationType::StraightFlush,
+            CombinationRanks::RoyalFlush => CombinationType::RoyalFlush,
+        }
+    }
+    /// Combination with these ranks, the inverse of `Combination::ranks`.
+    /// None when no hand has these ranks, such as ranks repeated among the kickers
+    /// or a straight below five high
+    pub fn combination(&self) -> Option<Combination> {
+        // ranks of distinct cards from the highest, none of them used by `made`
+        let valid = |ranks: &[u32], made: &[u32], most: usize| {
+            ranks.len() <= most
+                && ranks.iter().all(|&rank| rank < 13 && !made.contains(&rank))
+                && ranks.windows(2).all(|pair| pair[0] > pair[1])
+        };
+        let bits = |ranks: &[u32]| ranks.iter().fold(0, |bits, &rank| bits | 1 << rank);
+        // five ranks in a row would be a straight
+        let is_straight = |ranks: &[u32]| Combination::straight_bits(bits(ranks), false) != 0;
+        // a lone kicker is stored as its rank plus one, so that 0 means none
+        let kicker_bits = |kicker: Option<u32>| kicker.map_or(0, |rank| rank + 1);
+        let category = self.category() as u32;
+        let value = match self {
+            CombinationRanks::HighCard { ranks } => {
+                if !valid(ranks, &[], 5) || is_straight(ranks) {
+                    return None;
+                }
+                category | bits(ranks)
+            }
+            CombinationRanks::Flush { ranks } => {
+                if !valid(ranks, &[], 5) || ranks.len() != 5 || is_straight(ranks) {
+                    return None;
+                }
+                category | bits(ranks)
+            }
+            CombinationRanks::Pair {
+                pair: high,
+                kickers,
+            } => {
+                if !valid(&[*high], &[], 1) || !valid(kickers, &[*high], 3) {
+                    return None;
+                }
+                category | ((high + 1) << 13) | bits(kickers)
+            }
+            CombinationRanks::Tris {
+                tris: high,
+                kickers,
+            } => {
+                if !valid(&[*high], &[], 1) || !valid(kickers, &[*high], 2) {
+                    return None;
+                }
+                category | ((high + 1) << 13) | bits(kickers)
+            }
+            CombinationRanks::TwoPairs { high, low, kicker } => {
+                let kickers: Vec<u32> = kicker.iter().copied().collect();
+                if !valid(&[*high, *low], &[], 2) || !valid(&kickers, &[*high, *low], 1) {
+                    return None;
+                }
+                category | (bits(&[*high, *low]) << 6) | kicker_bits(*kicker)
+            }
+            CombinationRanks::Straight { high } => {
+                if !(3..13).contains(high) {
+                    return None;
+                }
+                category | high
+            }
+            CombinationRanks::StraightFlush { high } => {
+                if !(3..12).contains(high) {
+                    return None;
+                }
+                category | high
+            }
+            CombinationRanks::FullHouse { tris, pair } => {
+                if !valid(&[*tris], &[], 1) || !valid(&[*pair], &[*tris], 1) {
+                    return None;
+                }
+                category | ((tris + 1) << 13) | pair
+            }
+            CombinationRanks::Poker { poker, kicker } => {
+                let kickers: Vec<u32> = kicker.iter().copied().collect();
+                if !valid(&[*poker], &[], 1) || !valid(&kickers, &[*poker], 1) {
+                    return None;
+                }
+                category | ((poker + 1) << 13) | kicker_bits(*kicker)
+            }
+            CombinationRanks::RoyalFlush => category,
+        };
+        Some(Combination(value))
+    }
+}
+
+impl Combination {
+    /// Position of most significant bit in bits[0..13].
+    /// If bits is 0, 0 also is returned
+    fn msb(bits: u32) -> u32 {
+        assert!(bits < 1 << 13);
+        (0..13)
+            .rev()
+            .find(|&n| bits.wrapping_shr(n) == 1)
+            .unwrap_or(0)
+    }
+    /// Position of most significant bit in bits[0..13] plus one,
+    /// so that 0 is returned only if bits is 0
+    fn kicker(bits: u32) -> u32 {
+        if bits == 0 {
+            0
+        } else {
+            Combination::msb(bits) + 1
+        }
+    }
+    /// Keeps only up to n msb in bits[0..13]
+    fn keep_n_bits(bits: u32, n: usize) -> u32 {
+        let shift_amount = (0..13)
+            .rev()
+            .filter(|b| bits & (1 << b) != 0)
+            .take(n)
+            .last()
+            .unwrap_or(13);
+        bits.wrapping_shr(shift_amount) << shift_amount
+    }
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+    /// Combination of a value given by `as_u32`
+    pub(crate) fn from_u32(value: u32) -> Self {
+        Combination(value)
+    }
+    pub fn category(&self) -> CombinationType {
+        let v = self.as_u32();
+        assert!(v <= CombinationType::RoyalFlush as u32);
+        if v == CombinationType::RoyalFlush as u32 {
+            CombinationType::RoyalFlush
+        } else if v >= CombinationType::StraightFlush as u32 {
+            CombinationType::StraightFlush
+        } else if v >= CombinationType::Poker as u32 {
+            CombinationType::Poker
+        } else if v >= CombinationType::FullHouse as u32 {
+            CombinationType::FullHouse
+        } else if v >= CombinationType::Flush as u32 {
+            CombinationType::Flush
+        } else if v >= CombinationType::Straight as u32 {
+            CombinationType::Straight
+        } else if v >= CombinationType::Tris as u32 {
+            CombinationType::Tris
+        } else if v >= CombinationType::TwoPairs as u32 {
+            CombinationType::TwoPairs
+        } else if v >= CombinationType::Pair as u32 {
+            CombinationType::Pair
+        } else {
+            CombinationType::HighCard
+        }
+    }
+    pub fn name(&self) -> &str {
+        let v = self.as_u32();
+        assert!(v <= CombinationType::RoyalFlush as u32);
+        if v == CombinationType::RoyalFlush as u32 {
+            "RoyalFlush"
+        } else if v >= CombinationType::StraightFlush as u32 {
+            "StraightFlush"
+        } else if v >= CombinationType::Poker as u32 {
+            "Poker"
+        } else if v >= CombinationType::FullHouse as u32 {
+            "FullHouse"
+        } else if v >= CombinationType::Flush as u32 {
+            "Flush"
+        } else if v >= CombinationType::Straight as u32 {
+            "Straight"
+        } else if v >= CombinationType::Tris as u32 {
+            "Tris"
+        } else if v >= CombinationType::TwoPairs as u32 {
+            "TwoPairs"
+        } else if v >= CombinationType::Pair as u32 {
+            "Pair"
+        } else {
+            "HighCard"
+        }
+    }
+    /// Description of the combination with its ranks and kickers,
+    /// such as "Two pair, Kings and Sevens, Ace kicker" or "Straight, Ten high"
+    pub fn description(&self) -> String {
+        let (made, kickers) = self.rank_counts(false);
+        let kickers = match kickers.len() {
+            0 => String::new(),
+            1 => format!(", {} kicker", rank_name(kickers[0], false)),
+            _ => format!(
+                ", {} kickers",
+                kickers
+                    .iter()
+                    .map(|&rank| rank_name(rank, false))
+                    .collect::<Vec<_>>()
+                    .join("-")
+            ),
+        };
+        let rank =
+            |i: usize, plural: bool| made.get(i).map_or("", |&(rank, _)| rank_name(rank, plural));
+        match self.category() {
+            CombinationType::RoyalFlush => "Royal flush".to_string(),
+            CombinationType::StraightFlush => format!("Straight flush, {} high", rank(0, false)),
+            CombinationType::Poker => format!("Four of a kind, {}{}", rank(0, true), kickers),
+            CombinationType::FullHouse => {
+                format!("Full house, {} full of {}", rank(0, true), rank(1, true))
+            }
+            CombinationType::Flush => format!(
+                "Flush, {}",
+                made.iter()
+                    .map(|&(rank, _)| rank_name(rank, false))
+                    .collect::<Vec<_>>()
+                    .join("-")
+            ),
+            CombinationType::Straight => format!("Straight, {} high", rank(0, false)),
+            CombinationType::Tris => format!("Three of a kind, {}{}", rank(0, true), kickers),
+            CombinationType::TwoPairs => format!(
+                "Two pair, {} and {}{}",
+                rank(0, true),
+                rank(1, true),
+                kickers
+            ),
+            CombinationType::Pair => format!("Pair of {}{}", rank(0, true), kickers),
+            CombinationType::HighCard if made.is_empty() => "No cards".to_string(),
+            CombinationType::HighCard => format!("High card, {}{}", rank(0, false), kickers),
+        }
+    }
+    fn straight_bits(numbers: u32, short_deck: bool) -> u32 {
+        // add ace (numbers >> 12) to check for the minimal straight (A-5, or A-9 in short deck)
+        let low_ace = if short_deck {
+            (numbers >> 12) << 4
+        } else {
+            numbers >> 12
+        };
+        let numbers_ace = (numbers << 1) | low_ace;
+        numbers & numbers_ace & (numbers_ace << 1) & (numbers_ace << 2) & (numbers_ace << 3)
+    }
+    /// Value used to compare combinations in short deck, where flush beats full house
+    pub fn short_deck_strength(&self) -> u32 {
+        let flush = self.0 & CombinationType::Flush as u32;
+        let full_house = self.0 & CombinationType::FullHouse as u32;
+        (self.0 & !(flush | full_house)) | (flush << 1) | (full_house >> 1)
+    }
+    /// Ranks of the cards of the combination, each with how many cards of it are used,
+    /// then the ranks of the kickers, from the highest
+    fn rank_counts(&self, short_deck: bool) -> (Vec<(u32, u32)>, Vec<u32>) {
+        let straight = |high: u32| {
+            // the ace under the lowest straight
+            let lowest = if short_deck { 4 } else { 0 };
+            (0..5)
+                .map(|i| {
+                    if high >= lowest + i {
+                        (high - i, 1)
+                    } else {
+                        (12, 1)
+                    }
+                })
+                .collect()
+        };
+        match self.ranks() {
+            CombinationRanks::RoyalFlush => (straight(12), Vec::new()),
+            CombinationRanks::StraightFlush { high } | CombinationRanks::Straight { high } => {
+                (straight(high), Vec::new())
+            }
+            CombinationRanks::Poker { poker, kicker } => {
+                (vec![(poker, 4)], kicker.into_iter().collect())
+            }
+            CombinationRanks::FullHouse { tris, pair } => (vec![(tris, 3), (pair, 2)], Vec::new()),
+            CombinationRanks::Flush { ranks } => (
+                ranks.into_iter().map(|rank| (rank, 1)).collect(),
+                Vec::new(),
+            ),
+            CombinationRanks::Tris { tris, kickers } => (vec![(tris, 3)], kickers),
+            CombinationRanks::TwoPairs { high, low, kicker } => {
+                (vec![(high, 2), (low, 2)], kicker.into_iter().collect())
+            }
+            CombinationRanks::Pair { pair, kickers } => (vec![(pair, 2)], kickers),
+            CombinationRanks::HighCard { mut ranks } => {
+                let kickers = ranks.split_off(ranks.len().min(1));
+                (ranks.into_iter().map(|rank| (rank, 1)).collect(), kickers)
+            }
+        }
+    }
+    /// Number of distinct combinations of five cards
+    pub const CLASSES: u32 = 7462;
+    /// Every distinct combination of five cards, from the weakest
+    fn classes() -> &'static [u32] {
+        static CLASSES: OnceLock<Vec<u32>> = OnceLock::new();
+        CLASSES.get_or_init(|| {
+            let mut values: Vec<u32> = CardSet::all()
+                .subsets(5)
+                .map(|cards| cards.comb().0)
+                .collect();
+            values.sort_unstable();
+            values.dedup();
+            values
+        })
+    }
+    /// Standard equivalence class of the combination, from 1 for a royal flush to 7462
+    /// for 7-5-4-3-2, none when it is not a combination of five cards, such as a lone pair
+    pub fn class_index(&self) -> Option<u32> {
+        Combination::classes()
+            .binary_search(&self.0)
+            .ok()
+            .map(|position| Combination::CLASSES - position as u32)
+    }
+    /// Combination of an equivalence class given by `class_index`
+    pub fn from_class_index(index: u32) -> Option<Combination> {
+        if (1..=Combination::CLASSES).contains(&index) {
+            Some(Combination(
+                Combination::classes()[(Combination::CLASSES - index) as usize],
+            ))
+        } else {
+            None
+        }
+    }
+    /// Category and ranks of the cards of the combination
+    pub fn ranks(&self) -> CombinationRanks {
+        let low = self.0 & 0b1111111111111;
+        let high = ((self.0 >> 13) & 0b111111).wrapping_sub(1);
+        let ranks = |bits: u32| {
+            (0..13)
+                .rev()
+                .filter(|rank| bits & (1 << rank) != 0)
+                .collect()
+        };
+        match self.category() {
+            CombinationType::RoyalFlush => CombinationRanks::RoyalFlush,
+            CombinationType::StraightFlush => CombinationRanks::StraightFlush { high: low },
+            CombinationType::Poker => CombinationRanks::Poker {
+                poker: high,
+                kicker: low.checked_sub(1),
+            },
+            CombinationType::FullHouse => CombinationRanks::FullHouse {
+                tris: high,
+                pair: low,
+            },
+            CombinationType::Flush => CombinationRanks::Flush { ranks: ranks(low) },
+            CombinationType::Straight => CombinationRanks::Straight { high: low },
+            CombinationType::Tris => CombinationRanks::Tris {
+                tris: high,
+                kickers: ranks(low),
+            },
+            CombinationType::TwoPairs => {
+                let pairs: Vec<u32> = ranks((self.0 >> 6) & 0b1111111111111);
+                CombinationRanks::TwoPairs {
+                    high: pairs[0],
+                    low: pairs[1],
+                    kicker: (self.0 & 0b111111).checked_sub(1),
+                }
+            }
+            CombinationType::Pair => CombinationRanks::Pair {
+                pair: high,
+                kickers: ranks(low),
+            },
+            CombinationType::HighCard => CombinationRanks::HighCard { ranks: ranks(low) },
+        }
+    }
+    #[allow(clippy::identity_op)]
+    fn new(cards: CardSet, short_deck: bool) -> Self {
+        debug_assert!(cards.count_cards() <= 8);
+        let cards = cards.as_u64();
+        let suit_mask = 0b1111111111111;
+        let n1 = (cards & suit_mask) as u32;
+        let n2 = (cards.wrapping_shr(1 * 13) & suit_mask) as u32;
+        let n3 = (cards.wrapping_shr(2 * 13) & suit_mask) as u32;
+        let n4 = (cards.wrapping_shr(3 * 13) & suit_mask) as u32;
+        let (flush_count, flush_value) = (n1.count_ones(), n1)
+            .max((n2.count_ones(), n2))
+            .max((n3.count_ones(), n3))
+            .max((n4.count_ones(), n4));
+        let is_flush = flush_count >= 5;
+        if is_flush {
+            let straight_value = Combination::straight_bits(flush_value, short_deck);
+            let is_royal_flush = straight_value != 0;
+            if is_royal_flush {
+                // highest straight wins
+                return Combination(
+                    CombinationType::StraightFlush as u32 | Combination::msb(straight_value),
+                );
+            }
+        }
+        let numbers = n1 | n2 | n3 | n4;
+        let poker_bits = n1 & n2 & n3 & n4;
+        let is_poker = poker_bits != 0;
+        if is_poker {
+            let highest_poker = Combination::msb(poker_bits);
+            // highest poker or highest card not in poker
+            Combination(
+                CombinationType::Poker as u32
+                    | ((highest_poker + 1) << 13)
+                    | Combination::kicker(numbers & !(1 << highest_poker)),
+            )
+        } else {
+            let tris_bits = (n1 & n2 & n3) | (n1 & n2 & n4) | (n1 & n3 & n4) | (n2 & n3 & n4);
+            let highest_tris = Combination::msb(tris_bits);
+            let highest_tris_bit = tris_bits & (1 << highest_tris);
+            // all pairs that aren't also part of the highest tris_value
+            let pair_bits = ((n1 & n2) | (n1 & n3) | (n1 & n4) | (n2 & n3) | (n2 & n4) | (n3 & n4))
+                & !highest_tris_bit;
+            let is_tris = highest_tris_bit != 0;
+            let is_pair = pair_bits != 0;
+            // in short deck a flush beats a full house made with the same cards,
+            // which only sets of more than seven cards can hold
+            let is_full_house = is_tris && is_pair && !(short_deck && is_flush);
+            if is_full_house {
+                // highest tries or highest pair
+                Combination(
+                    CombinationType::FullHouse as u32
+                        | ((highest_tris + 1) << 13)
+                        | Combination::msb(pair_bits),
+                )
+            } else if is_flush {
+                // 5 highest cards
+                Combination(
+                    CombinationType::Flush as u32 | Combination::keep_n_bits(flush_value, 5),
+                )
+            } else {
+                let straight_bits = Combination::straight_bits(numbers, short_deck);
+                let is_straight = straight_bits != 0;
+                if is_straight {
+                    // highest straight
+                    Combination(CombinationType::Straight as u32 | Combination::msb(straight_bits))
+                } else if is_tris {
+                    Combination(
+                        CombinationType::Tris as u32| // uses bit 26
+                         ((highest_tris + 1) << 13) // uses bits 18 to 13
+                            | Combination::keep_n_bits(numbers & !highest_tris_bit, 2), // highest tris or 2 highest cards
+                    )
+                } else if pair_bits != 0 {
+                    if pair_bits.count_ones() >= 2 {
+                        let first_2_pairs = Combination::keep_n_bits(pair_bits, 2);
+                        Combination(
+                            CombinationType::TwoPairs as u32  // uses bit 25
+                            | (first_2_pairs << 6) // uses bits 19 to 6
+                            | Combination::kicker(numbers & !first_2_pairs), // highest pair or second highest pair or highest card
+                        )
+                    } else {
+                        Combination(
+                            CombinationType::Pair as u32 // uses bit 24
+                            | ((Combination::msb(pair_bits) + 1) << 13) // uses bits 18 to 13
+                            | Combination::keep_n_bits(numbers & !pair_bits, 3), // pair or highest 3 cards
+                        )
+                    }
+                } else {
+                    Combination(
+                        CombinationType::HighCard as u32 | Combination::keep_n_bits(numbers, 5), // highest 5 cards
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// English name of a rank from 0 for deuces to 12 for aces
+fn rank_name(rank: u32, plural: bool) -> &'static str {
+    const NAMES: [(&str, &str); 13] = [
+        ("Two", "Twos"),
+        ("Three", "Threes"),
+        ("Four", "Fours"),
+        ("Five", "Fives"),
+        ("Six", "Sixes"),
+        ("Seven", "Sevens"),
+        ("Eight", "Eights"),
+        ("Nine", "Nines"),
+        ("Ten", "Tens"),
+        ("Jack", "Jacks"),
+        ("Queen", "Queens"),
+        ("King", "Kings"),
+        ("Ace", "Aces"),
+    ];
+    let (singular, plural_name) = NAMES[rank as usize];
+    if plural {
+        plural_name
+    } else {
+        singular
+    }
+}
+
+/// Five cards making a combination, or all the cards when there are fewer
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct BestHand {
+    /// cards making the category, such as the two cards of a pair or the five of a straight
+    pub cards: CardSet,
+    /// cards completing the five, from the highest
+    pub kickers: CardSet,
+}
+
+impl BestHand {
+    fn new(cards: CardSet, short_deck: bool) -> Self {
+        let comb = Combination::new(cards, short_deck);
+        let (made, kickers) = comb.rank_counts(short_deck);
+        // a flush is made with the cards of the suit holding five of them
+        let suits = match comb.category() {
+            CombinationType::Flush
+            | CombinationType::StraightFlush
+            | CombinationType::RoyalFlush => {
+                let suit = (0..4)
+                    .find(|suit| {
+                        ((cards.as_u64() >> (13 * suit)) & 0b1111111111111).count_ones() >= 5
+                    })
+                    .unwrap();
+                suit..suit + 1
+            }
+            _ => 0..4,
+        };
+        let mut left = cards;
+        let mut take = |rank: u32, count: u32| {
+            let mut taken = CardSet::none();
+            for suit in suits.clone() {
+                let card = CardSet::one(rank + 13 * suit);
+                if taken.count_cards() < count && !(left & card).is_empty() {
+                    taken |= card;
+                }
+            }
+            left = left & !taken;
+            taken
+        };
+        let made = made
+            .into_iter()
+            .fold(CardSet::none(), |set, (rank, count)| {
+                set | take(rank, count)
+            });
+        let kickers = kickers
+            .into_iter()
+            .fold(CardSet::none(), |set, rank| set | take(rank, 1));
+        BestHand {
+            cards: made,
+            kickers,
+        }
+    }
+    /// All the five cards
+    pub fn all(&self) -> CardSet {
+        self.cards | self.kickers
+    }
+}
+
+impl CardSet {
+    pub fn comb(&self) -> Combination {
+        Combination::new(*self, false)
+    }
+    /// Combination of short deck cards, where A-6-7-8-9 is the minimal straight
+    pub fn short_deck_comb(&self) -> Combination {
+        Combination::new(*self, true)
+    }
+    /// Five cards making `comb`
+    pub fn best_hand(&self) -> BestHand {
+        BestHand::new(*self, false)
+    }
+    /// Five cards making `short_deck_comb`
+    pub fn short_deck_best_hand(&self) -> BestHand {
+        BestHand::new(*self, true)
+    }
+}
+
+#[test]
+fn msb_test() {
+    assert_eq!(Combination::msb(0b10100), 4);
+    assert_eq!(Combination::msb(0b1111111111111), 12);
+    assert_eq!(Combination::msb(0b1), 0);
+    assert_eq!(Combination::msb(0b0), 0);
+}
+
+#[test]
+fn keep_n_bits_test() {
+    assert_eq!(Combination::keep_n_bits(0b10100, 0), 0b0);
+    assert_eq!(Combination::keep_n_bits(0b10100, 1), 0b10000);
+    assert_eq!(Combination::keep_n_bits(0b10100, 2), 0b10100);
+    assert_eq!(Combination::keep_n_bits(0b10110, 2), 0b10100);
+    assert_eq!(Combination::keep_n_bits(0b10110, 3), 0b10110);
+    assert_eq!(Combination::keep_n_bits(0b10100, 3), 0b10100);
+    assert_eq!(Combination::keep_n_bits(0b10100, 4), 0b10100);
+    assert_eq!(Combination::keep_n_bits(0b00000, 4), 0b00000);
+}
@@ -0,0 +1,353 @@
+use super::cardset::CardSet;
+use super::combination::{Combination, CombinationRanks};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Way combinations are computed, every one giving the same values
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EvaluatorKind {
+    /// bit manipulation of `CardSet::comb`
+    Bits,
+    /// lookup tables of `table_comb`, for up to 7 cards
+    Table,
+}
+
+/// Computes the combination of a set of cards, every implementation giving the same value
+pub trait Evaluator {
+    fn evaluate(&self, cards: CardSet) -> Combination;
+}
+
+/// Bit manipulation of `CardSet::comb`
+pub struct BitsEvaluator;
+
+impl Evaluator for BitsEvaluator {
+    fn evaluate(&self, cards: CardSet) -> Combination {
+        cards.comb()
+    }
+}
+
+/// Lookup tables of `table_comb`, for up to 7 cards
+pub struct TableEvaluator;
+
+impl Evaluator for TableEvaluator {
+    fn evaluate(&self, cards: CardSet) -> Combination {
+        table_comb(cards)
+    }
+}
+
+/// Slow reference ranking every subset of five cards on its own,
+/// to check the other evaluators against
+pub struct NaiveEvaluator;
+
+impl NaiveEvaluator {
+    /// Combination of at most five cards
+    fn rank(cards: CardSet) -> Combination {
+        let bits = cards.as_u64();
+        let mut counts = [0u32; 13];
+        for card in (0..52).filter(|card| bits & (1 << card) != 0) {
+            counts[card % 13] += 1;
+        }
+        // ranks by number of cards, then from the highest
+        let mut groups: Vec<(u32, u32)> = (0..13u32)
+            .filter(|&rank| counts[rank as usize] > 0)
+            .map(|rank| (counts[rank as usize], rank))
+            .collect();
+        groups.sort_unstable_by(|a, b| b.cmp(a));
+        let shape: Vec<u32> = groups.iter().map(|&(count, _)| count).collect();
+        let ranks: Vec<u32> = groups.iter().map(|&(_, rank)| rank).collect();
+        let is_flush = cards.count_cards() == 5
+            && (0..4).any(|suit| ((bits >> (13 * suit)) & SUIT_MASK).count_ones() == 5);
+        let straight = if ranks.len() < 5 {
+            None
+        } else if ranks[0] - ranks[4] == 4 {
+            Some(ranks[0])
+        } else if ranks == [12, 3, 2, 1, 0] {
+            Some(3)
+        } else {
+            None
+        };
+        let kicker = |i: usize| ranks.get(i).copied();
+        match (straight, is_flush, shape.as_slice()) {
+            (Some(12), true, _) => CombinationRanks::RoyalFlush,
+            (Some(high), true, _) => CombinationRanks::StraightFlush { high },
+            (_, _, [4, ..]) => CombinationRanks::Poker {
+                poker: ranks[0],
+                kicker: kicker(1),
+            },
+            (_, _, [3, 2]) => CombinationRanks::FullHouse {
+                tris: ranks[0],
+                pair: ranks[1],
+            },
+            (_, true, _) => CombinationRanks::Flush { ranks },
+            (Some(high), _, _) => CombinationRanks::Straight { high },
+            (_, _, [3, ..]) => CombinationRanks::Tris {
+                tris: ranks[0],
+                kickers: ranks[1..].to_vec(),
+            },
+            (_, _, [2, 2, ..]) => CombinationRanks::TwoPairs {
+                high: ranks[0],
+                low: ranks[1],
+                kicker: kicker(2),
+            },
+            (_, _, [2, ..]) => CombinationRanks::Pair {
+                pair: ranks[0],
+                kickers: ranks[1..].to_vec(),
+            },
+            _ => CombinationRanks::HighCard { ranks },
+        }
+        .combination()
+        .unwrap()
+    }
+}
+
+impl Evaluator for NaiveEvaluator {
+    fn evaluate(&self, cards: CardSet) -> Combination {
+        if cards.count_cards() <= 5 {
+            NaiveEvaluator::rank(cards)
+        } else {
+            cards.subsets(5).map(NaiveEvaluator::rank).max().unwrap()
+        }
+    }
+}
+
+impl EvaluatorKind {
+    pub fn comb(self, cards: CardSet) -> Combination {
+        match self {
+            EvaluatorKind::Bits => BitsEvaluator.evaluate(cards),
+            EvaluatorKind::Table => TableEvaluator.evaluate(cards),
+        }
+    }
+}
+
+impl FromStr for EvaluatorKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "bits" => Ok(EvaluatorKind::Bits),
+            "table" => Ok(EvaluatorKind::Table),
+            _ => Err(format!("unknown evaluator '{}', expected bits or table", s)),
+        }
+    }
+}
+
+const SUIT_MASK: u64 = 0b1111111111111;
+/// Most cards of a hand of `table_comb`
+const MAX_CARDS: usize = 7;
+
+/// Tables built on first use, from combinations computed by `CardSet::comb`
+struct Tables {
+    /// combination of the cards of a suit holding at least five of them, by their ranks
+    flush: Vec<u32>,
+    /// ranks of a suit with 3 bits each, so that adding the suits counts the cards of each rank
+    spread: Vec<u64>,
+    /// `hash_steps[count][ranks][cards]` is the number of rank counts of `cards` cards
+    /// among `ranks + 1` ranks starting with fewer than `count` cards in the first rank
+    hash_steps: [[[u32; MAX_CARDS + 1]; 13]; 5],
+    /// start in `no_flush` of the hands of each number of cards
+    offsets: [u32; MAX_CARDS + 1],
+    /// combination of the cards without a flush, by the hash of their rank counts
+    no_flush: Vec<u32>,
+}
+
+impl Tables {
+    fn new() -> Tables {
+        // ways[ranks][cards]: rank counts of `cards` cards among `ranks` ranks
+        let mut ways = [[0u32; MAX_CARDS + 1]; 14];
+        ways[0][0] = 1;
+        for ranks in 1..14 {
+            for cards in 0..=MAX_CARDS {
+                ways[ranks][cards] = (0..=cards.min(4))
+                    .map(|count| ways[ranks - 1][cards - count])
+                    .sum();
+            }
+        }
+        let mut hash_steps = [[[0; MAX_CARDS + 1]; 13]; 5];
+        for count in 1..5 {
+            for ranks in 0..13 {
+                for cards in 0..=MAX_CARDS {
+                    hash_steps[count][ranks][cards] = hash_steps[count - 1][ranks][cards]
+                        + if count - 1 <= cards {
+                            ways[ranks][cards - (count - 1)]
+                        } else {
+                            0
+                        };
+                }
+            }
+        }
+        let mut offsets = [0; MAX_CARDS + 1];
+        for cards in 1..=MAX_CARDS {
+            offsets[cards] = offsets[cards - 1] + ways[13][cards - 1];
+        }
+
+        let cards_of = |bits: u64| {
+            (0..52)
+                .filter(|i| bits & (1 << i) != 0)
+                .fold(CardSet::none(), |cards, i| cards | CardSet::one(i))
+        };
+        let flush = (0..1 << 13)
+            .map(|ranks: u64| {
+                if (5..=MAX_CARDS as u32).contains(&ranks.count_ones()) {
+                    cards_of(ranks).comb().as_u32()
+                } else {
+                    0
+                }
+            })
+            .collect();
+        let spread = (0..1 << 13)
+            .map(|ranks: u64| {
+                (0..13)
+                    .filter(|rank| ranks & (1 << rank) != 0)
+                    .map(|rank| 1 << (3 * rank))
+                    .sum()
+            })
+            .collect();
+
+        let mut tables = Tables {
+            flush,
+            spread,
+            hash_steps,
+            offsets,
+            no_flush: vec![0; (offsets[MAX_CARDS] + ways[13][MAX_CARDS]) as usize],
+        };
+        let mut counts = [0; 13];
+        tables.fill_no_flush(&mut counts, 0, 0, &cards_of);
+        tables
+    }
+    /// Fills `no_flush` with every rank count from `rank` on, given the counts of the ranks
+    /// before it adding up to `cards`
+    fn fill_no_flush(
+        &mut self,
+        counts: &mut [u64; 13],
+        rank: usize,
+        cards: usize,
+        cards_of: &impl Fn(u64) -> CardSet,
+    ) {
+        if rank == 13 {
+            // spread the cards among the suits, so that no suit has five of them
+            let mut suit_cards = [0u64; 4];
+            let mut bits = 0;
+            for (rank, &count) in counts.iter().enumerate() {
+                let mut suits = [0, 1, 2, 3];
+                suits.sort_by_key(|&suit| suit_cards[suit]);
+                for &suit in &suits[..count as usize] {
+                    suit_cards[suit] += 1;
+                    bits |= 1 << (rank + suit * 13);
+                }
+            }
+            let packed = counts
+                .iter()
+                .enumerate()
+                .map(|(rank, &count)| count << (3 * rank))
+                .sum();
+            let index = self.hash(packed, cards);
+            self.no_flush[index] = cards_of(bits).comb().as_u32();
+            return;
+        }
+        for count in 0..=4.min(MAX_CARDS - cards) {
+            counts[rank] = count as u64;
+            self.fill_no_flush(counts, rank + 1, cards + count, cards_of);
+        }
+        counts[rank] = 0;
+    }
+    /// Position of the rank counts `packed` 3 bits each, adding up to `cards`,
+    /// among all the rank counts
+    fn hash(&self, packed: u64, mut cards: usize) -> usize {
+        let mut index = self.offsets[cards];
+        for rank in 0..13 {
+            if cards == 0 {
+                break;
+            }
+            let count = ((packed >> (3 * rank)) & 0b111) as usize;
+            index += self.hash_steps[count][12 - rank][cards];
+            cards -= count;
+        }
+        index as usize
+    }
+}
+
+fn tables() -> &'static Tables {
+    static TABLES: OnceLock<Tables> = OnceLock::new();
+    TABLES.get_or_init(Tables::new)
+}
+
+/// Same combination as `CardSet::comb` looked up in precomputed tables, for up to 7 cards
+pub fn table_comb(cards: CardSet) -> Combination {
+    debug_assert!(cards.count_cards() as usize <= MAX_CARDS);
+    let tables = tables();
+    let bits = cards.as_u64();
+    let mut packed = 0;
+    for suit in 0..4 {
+        let ranks = (bits >> (13 * suit)) & SUIT_MASK;
+        // with up to seven cards a flush beats whatever the other cards make
+        if ranks.count_ones() >= 5 {
+            return Combination::from_u32(tables.flush[ranks as usize]);
+        }
+        packed += tables.spread[ranks as usize];
+    }
+    Combination::from_u32(tables.no_flush[tables.hash(packed, cards.count_cards() as usize)])
+}
+
+#[test]
+fn table_matches_bits() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    for count in 0..=6 {
+        if count == 6 && cfg!(debug_assertions) {
+            break;
+        }
+        for cards in CardSet::all().subsets(count) {
+            assert_eq!(table_comb(cards).as_u32(), cards.comb().as_u32());
+        }
+    }
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..200_000 {
+        let cards = CardSet::all().draw(7, &mut rng);
+        assert_eq!(table_comb(cards).as_u32(), cards.comb().as_u32());
+    }
+}
+
+/// The lookup tables give the same combination as the bit evaluator for every 7-card hand
+#[test]
+#[ignore]
+fn table_matches_bits_all_hands() {
+    use rayon::prelude::*;
+
+    let mismatches = CardSet::all()
+        .subsets(7)
+        .par_bridge()
+        .filter(|&cards| table_comb(cards).as_u32() != cards.comb().as_u32())
+        .count();
+    assert_eq!(mismatches, 0);
+}
+
+#[test]
+fn evaluators_agree() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let evaluators: [&dyn Evaluator; 3] = [&BitsEvaluator, &TableEvaluator, &NaiveEvaluator];
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..20000 {
+        let count = rng.gen_range(0..=7);
+        let mut deck = CardSet::all();
+        let a = deck.draw(count, &mut rng);
+        let b = deck.draw(count, &mut rng);
+        let values: Vec<(u32, u32)> = evaluators
+            .iter()
+            .map(|evaluator| {
+                (
+                    evaluator.evaluate(a).as_u32(),
+                    evaluator.evaluate(b).as_u32(),
+                )
+            })
+            .collect();
+        // same values, so the same ordering of any two hands
+        assert!(
+            values.iter().all(|&value| value == values[0]),
+            "{:?} {:?} {:?}",
+            a,
+            b,
+            values
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use super::cardset::CardSet;
+use super::combination::{BestHand, Combination};
+use super::evaluator::EvaluatorKind;
+use super::low::Low;
+use std::str::FromStr;
+
+/// Poker variant, deciding how many cards each player holds and how hands are made
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Game {
+    /// Texas Holdem, best five of the two hole cards and the table
+    Holdem,
+    /// Omaha with four hole cards, exactly two of them and three of the table
+    Omaha4,
+    /// Omaha with five hole cards, exactly two of them and three of the table
+    Omaha5,
+    /// Holdem without the cards from 2 to 5, where flush beats full house
+    /// and A-6-7-8-9 is a straight
+    ShortDeck,
+    /// Seven card stud, best five of the seven cards of each player, without a table
+    Stud,
+    /// Omaha with four hole cards, the pot split between the high and the 8-or-better low
+    Omaha4HiLo,
+    /// Omaha with five hole cards, the pot split between the high and the 8-or-better low
+    Omaha5HiLo,
+    /// Seven card stud, the pot split between the high and the 8-or-better low
+    StudHiLo,
+}
+
+impl Game {
+    /// Cards dealt to each player
+    pub fn hand_cards(self) -> u32 {
+        match self {
+            Game::Holdem | Game::ShortDeck => 2,
+            Game::Omaha4 | Game::Omaha4HiLo => 4,
+            Game::Omaha5 | Game::Omaha5HiLo => 5,
+            Game::Stud | Game::StudHiLo => 7,
+        }
+    }
+    /// Cards dealt on the table
+    pub fn table_cards(self) -> u32 {
+        match self {
+            Game::Stud | Game::StudHiLo => 0,
+            _ => 5,
+        }
+    }
+    /// Cards the game is played with
+    pub fn deck(self) -> CardSet {
+        match self {
+            Game::ShortDeck => (0..4)
+                .flat_map(|suit| (4..13).map(move |rank| rank + suit * 13))
+                .fold(CardSet::none(), |deck, index| deck | CardSet::one(index)),
+            _ => CardSet::all(),
+        }
+    }
+    /// Whether half of the pot goes to the best 8-or-better low, when there is one
+    pub fn is_hilo(self) -> bool {
+        matches!(self, Game::Omaha4HiLo | Game::Omaha5HiLo | Game::StudHiLo)
+    }
+    /// Value to compare combinations with, the higher the better
+    pub fn strength(self, combination: Combination) -> u32 {
+        match self {
+            Game::ShortDeck => combination.short_deck_strength(),
+            _ => combination.as_u32(),
+        }
+    }
+    /// Best combination of `hand` with `table`.
+    /// In Omaha, with fewer than three cards on the table all of them are used
+    pub fn evaluate(self, hand: CardSet, table: CardSet) -> Combination {
+        self.evaluate_with(EvaluatorKind::Bits, hand, table)
+    }
+    /// Same as `evaluate`, computing the combinations with `evaluator`.
+    /// Short deck combinations are always computed by `CardSet::short_deck_comb`
+    pub fn evaluate_with(
+        self,
+        evaluator: EvaluatorKind,
+        hand: CardSet,
+        table: CardSet,
+    ) -> Combination {
+        match self {
+            Game::Holdem | Game::Stud | Game::StudHiLo => evaluator.comb(hand | table),
+            Game::ShortDeck => (hand | table).short_deck_comb(),
+            Game::Omaha4 | Game::Omaha5 | Game::Omaha4HiLo | Game::Omaha5HiLo => {
+                omaha_hands(hand, table)
+                    .into_iter()
+                    .map(|cards| evaluator.comb(cards))
+                    .max()
+                    .unwrap()
+            }
+        }
+    }
+    /// Five cards making the combination given by `evaluate`
+    pub fn best_hand(self, hand: CardSet, table: CardSet) -> BestHand {
+        match self {
+            Game::Holdem | Game::Stud | Game::StudHiLo => (hand | table).best_hand(),
+            Game::ShortDeck => (hand | table).short_deck_best_hand(),
+            Game::Omaha4 | Game::Omaha5 | Game::Omaha4HiLo | Game::Omaha5HiLo => {
+                omaha_hands(hand, table)
+                    .into_iter()
+                    .max_by_key(|cards| cards.comb())
+                    .unwrap()
+                    .best_hand()
+            }
+        }
+    }
+    /// Best 8-or-better low of `hand` with `table`, none if there is no low or the game
+    /// is not played hi/lo
+    pub fn low(self, hand: CardSet, table: CardSet) -> Option<Low> {
+        match self {
+            Game::StudHiLo => (hand | table).low(),
+            Game::Omaha4HiLo | Game::Omaha5HiLo => omaha_hands(hand, table)
+                .into_iter()
+                .filter_map(|cards| cards.low())
+                .max(),
+            _ => None,
+        }
+    }
+}
+
+/// Every hand of exactly two cards of `hand` and three of `table`,
+/// an incomplete hand or table being used whole
+fn omaha_hands(hand: CardSet, table: CardSet) -> Vec<CardSet> {
+    let (hand_cards, hand_count) = single_cards(hand);
+    let holes = if hand_count < 2 {
+        vec![hand]
+    } else {
+        pairs(&hand_cards[..hand_count])
+    };
+    let (table_cards, table_count) = single_cards(table);
+    let boards = if table_count < 3 {
+        vec![table]
+    } else {
+        triples(&table_cards[..table_count])
+    };
+    holes
+        .iter()
+        .flat_map(|&hole| boards.iter().map(move |&board| hole | board))
+        .collect()
+}
+
+/// Each card of `cards` alone, up to five of them
+fn single_cards(cards: CardSet) -> ([CardSet; 5], usize) {
+    let mut singles = [CardSet::none(); 5];
+    let mut bits = cards.as_u64();
+    let mut count = 0;
+    while bits != 0 && count < 5 {
+        singles[count] = CardSet::one(bits.trailing_zeros());
+        bits &= bits - 1;
+        count += 1;
+    }
+    (singles, count)
+}
+
+fn pairs(cards: &[CardSet]) -> Vec<CardSet> {
+    let mut pairs = Vec::with_capacity(10);
+    for (i, &first) in cards.iter().enumerate() {
+        for &second in &cards[i + 1..] {
+            pairs.push(first | second);
+        }
+    }
+    pairs
+}
+
+fn triples(cards: &[CardSet]) -> Vec<CardSet> {
+    let mut triples = Vec::with_capacity(10);
+    for (i, &first) in cards.iter().enumerate() {
+        triples.extend(pairs(&cards[i + 1..]).into_iter().map(|pair| first | pair));
+    }
+    triples
+}
+
+impl FromStr for Game {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "holdem" => Ok(Game::Holdem),
+            "omaha" | "plo" | "plo4" => Ok(Game::Omaha4),
+            "plo5" => Ok(Game::Omaha5),
+            "shortdeck" | "short" | "6+" => Ok(Game::ShortDeck),
+            "stud" => Ok(Game::Stud),
+            "plo8" | "plo4-8" => Ok(Game::Omaha4HiLo),
+            "plo5-8" => Ok(Game::Omaha5HiLo),
+            "stud8" => Ok(Game::StudHiLo),
+            _ => Err(format!(
+                "unknown game '{}', expected holdem, plo4, plo5, shortdeck, stud, plo8, plo5-8 \
+                 or stud8",
+                s
+            )),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! Texas Holdem cards, hand evaluation and equity computation.
+//!
+//! A `simulation::Scenario` describes what is known of a game, `simulation::Engine::run`
+//! enumerates or simulates it returning a `simulation::SimulationResult` with the
+//! wins, ties and equity of every player.
+pub mod cardset;
+pub mod combination;
+pub mod evaluator;
+pub mod game;
+pub mod low;
+pub mod odds;
+pub mod range;
+pub mod simulation;
+#[cfg(test)]
+mod tests;
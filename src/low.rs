@@ -0,0 +1,48 @@
+use super::cardset::CardSet;
+
+/// Ace-to-five low hand qualifying for 8-or-better: five distinct cards from ace to eight,
+/// straights and flushes not counting. The higher the value the better the low.
+#[derive(Eq, PartialEq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub struct Low(u32);
+
+impl Low {
+    /// Ranks of the low, bit 0 for the ace up to bit 7 for the eight
+    fn ranks(&self) -> u32 {
+        0xff - self.0
+    }
+    /// Best low among `cards`, none if they have fewer than five distinct cards up to eight
+    fn new(cards: CardSet) -> Option<Self> {
+        let cards = cards.as_u64();
+        let suit_mask = 0b1111111111111;
+        let numbers = (0..4).fold(0, |numbers, suit| {
+            numbers | (cards.wrapping_shr(suit * 13) & suit_mask) as u32
+        });
+        // ace moves below the two, cards above eight are dropped
+        let mut lows = ((numbers & 0b1111111) << 1) | (numbers >> 12);
+        if lows.count_ones() < 5 {
+            return None;
+        }
+        // keep the five lowest cards
+        while lows.count_ones() > 5 {
+            lows &= !(1 << (31 - lows.leading_zeros()));
+        }
+        // fewer and lower ranks mean a better low
+        Some(Low(0xff - lows))
+    }
+    /// Cards of the low from the highest, such as "8-6-4-3-A"
+    pub fn name(&self) -> String {
+        let ranks = self.ranks();
+        (0..8)
+            .rev()
+            .filter(|rank| ranks & (1 << rank) != 0)
+            .map(|rank| "A2345678".chars().nth(rank).unwrap().to_string())
+            .collect::<Vec<String>>()
+            .join("-")
+    }
+}
+
+impl CardSet {
+    pub fn low(&self) -> Option<Low> {
+        Low::new(*self)
+    }
+}
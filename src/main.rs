@@ -1,90 +1,94 @@
-mod poker;
 use argh::FromArgs;
 use poker::cardset::{CardParseError, CardSet};
-use rayon::prelude::*;
-use std::{str::FromStr, time::Instant};
-
-/// Max cards allowed in player hand
-const MAX_HAND: u32 = 2;
-/// Max cards allowed in table
-const MAX_TABLE: u32 = 5;
-
-fn simulate(hand: CardSet, table: CardSet, players: u32, games: u32) -> u32 {
-    assert!(players >= 2 && players <= 8);
-    let hc = hand.count_cards();
-    assert!(hc <= MAX_HAND);
-    assert!((hand & table).is_empty());
-    let hand_draw_count = MAX_HAND - hc;
-    let tc = table.count_cards();
-    assert!(tc <= MAX_TABLE);
-    let deck = !(hand | table);
-    let table_draw_count = MAX_TABLE - tc;
-    let opponents = players - 1;
-    (0..games)
-        .into_par_iter()
-        .map(|_| {
-            let mut deck = deck;
-            let table = table | deck.draw(table_draw_count);
-            let my_comb = (hand | deck.draw(hand_draw_count) | table).comb();
-            for _ in 0..opponents {
-                let player_comb = (deck.draw(MAX_HAND) | table).comb();
-                if player_comb > my_comb {
-                    return 0;
-                }
-            }
-            1
-        })
-        .sum()
-}
-
-fn print_simulation(hand: CardSet, table: CardSet, players: u32, games: u32) {
-    assert!(players >= 2 && players <= 8);
-    let hc = hand.count_cards();
-    assert!(hc <= MAX_HAND);
-    let hand_draw_count = MAX_HAND - hc;
-    let table_cards_count = table.count_cards();
-    assert!(table_cards_count <= MAX_TABLE);
-    assert!((hand & table).is_empty());
-    let table_draw_count = MAX_TABLE - table_cards_count;
-    let deck = !(hand | table);
-    let mut results = Vec::with_capacity(players as usize);
-    let mut rows = Vec::with_capacity(players as usize);
+use poker::combination::{Combination, CombinationType};
+use poker::evaluator::EvaluatorKind;
+use poker::game::Game;
+use poker::low::Low;
+use poker::odds::PotOdds;
+use poker::range::{Range, RangeParseError};
+use poker::simulation::{
+    enumerate, hit_probability, next_card_runouts, outs, runout_results, simulate,
+    simulate_adaptive, weighted_average, Engine, Estimate, Holding, Out, Runout, Scenario,
+    ScenarioError, SimulationResult, Stop,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use std::{
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+fn print_simulation(scenario: &Scenario, games: u32, seed: u64) {
+    let rng = &mut StdRng::seed_from_u64(seed);
+    let deck = scenario.deck();
+    let players = scenario.players();
+    let mut hands = Vec::with_capacity(players);
+    let mut rows = Vec::with_capacity(players);
 
     for round in 0..games {
-        results.clear();
+        hands.clear();
         rows.clear();
         let mut deck = deck;
-        let hand = hand | deck.draw(hand_draw_count);
-        let table = table | deck.draw(table_draw_count);
-
-        results.push((hand, (hand | table).comb()));
-        for _ in 0..players - 1 {
-            let player_cards = deck.draw(MAX_HAND);
-            let player_comb = (player_cards | table).comb();
-            results.push((player_cards, player_comb));
-        }
-        let winning_combination = results.iter().map(|v| v.1).max().unwrap();
-        let you_won = winning_combination == results[0].1;
+        let table = scenario.deal(rng, &mut deck, &mut hands);
+        let results: Vec<(CardSet, Combination, Option<Low>)> = hands
+            .iter()
+            .map(|&hand| {
+                (
+                    hand,
+                    scenario.game.evaluate_with(scenario.evaluator, hand, table),
+                    scenario.game.low(hand, table),
+                )
+            })
+            .collect();
+        let winning_combination = results
+            .iter()
+            .map(|v| v.1)
+            .max_by_key(|&comb| scenario.game.strength(comb))
+            .unwrap();
+        let winning_low = results.iter().filter_map(|v| v.2).max();
+        let you_won = winning_combination == results[0].1
+            || (winning_low.is_some() && winning_low == results[0].2);
 
-        for &(cards, comb) in results.iter() {
+        for &(cards, comb, low) in results.iter() {
             let won = winning_combination == comb;
+            let won_low = winning_low.is_some() && winning_low == low;
+            let best = scenario.game.best_hand(cards, table);
             rows.push((
                 format!("    {:?}", cards),
-                format!("{}", comb.name()),
-                if won { "[W]" } else { "" },
+                if best.kickers.is_empty() {
+                    format!("[{:?}]", best.cards)
+                } else {
+                    format!("[{:?}] {:?}", best.cards, best.kickers)
+                },
+                match low {
+                    Some(low) => format!("{} / {}", comb.description(), low.name()),
+                    None => comb.description(),
+                },
+                format!(
+                    "{}{}",
+                    if won { "[W]" } else { "" },
+                    if won_low { "[L]" } else { "" }
+                ),
             ));
         }
-        println!("{} ({:?})\n", if you_won { "WON" } else { "LOST" }, table);
+        print!("{} ({:?})", if you_won { "WON" } else { "LOST" }, table);
+        if scenario.dead.is_empty() {
+            println!("\n");
+        } else {
+            println!(" dead ({:?})\n", scenario.dead);
+        }
         let padding_1 = rows.iter().map(|row| row.0.chars().count()).max().unwrap();
         let padding_2 = rows.iter().map(|row| row.1.chars().count()).max().unwrap();
+        let padding_3 = rows.iter().map(|row| row.2.chars().count()).max().unwrap();
         for row in &rows {
             println!(
-                "{:<w1$}   {:<w2$}   {}",
+                "{:<w1$}   {:<w2$}   {:<w3$}   {}",
                 row.0,
                 row.1,
                 row.2,
+                row.3,
                 w1 = padding_1,
-                w2 = padding_2
+                w2 = padding_2,
+                w3 = padding_3
             );
         }
         if round < games - 1 {
@@ -94,105 +98,614 @@ fn print_simulation(hand: CardSet, table: CardSet, players: u32, games: u32) {
     println!("\n");
 }
 
+/// Prints wins, ties and equity of each player, like an equilator does,
+/// and with `hilo` the scoops instead of wins and ties, and the equity of the high and of the low
+fn print_players(names: &[String], result: &SimulationResult, hilo: bool) {
+    let padding = names.iter().map(|name| name.chars().count()).max().unwrap();
+    println!(
+        "\n{:<8}{:<w$}   {:>7}{}   {:>7}{}{}",
+        "player",
+        "hand",
+        if hilo { "scoop" } else { "wins" },
+        if hilo { "" } else { "      ties" },
+        "equity",
+        if result.exact { "" } else { "     ±95%" },
+        if hilo { "      high       low" } else { "" },
+        w = padding
+    );
+    for (i, name) in names.iter().enumerate() {
+        let equity = result.equity(i);
+        println!(
+            "{:<8}{:<w$}   {:>6.2}%{}   {:>6.2}%{}{}",
+            if i == 0 {
+                "you".to_string()
+            } else {
+                (i + 1).to_string()
+            },
+            name,
+            result.win(i).value * 100.0,
+            if hilo {
+                String::new()
+            } else {
+                format!("   {:>6.2}%", result.tie(i).value * 100.0)
+            },
+            equity.value * 100.0,
+            if result.exact {
+                String::new()
+            } else {
+                format!("   {:>5.2}%", equity.margin() * 100.0)
+            },
+            if hilo {
+                format!(
+                    "   {:>6.2}%   {:>6.2}%",
+                    result.high_equity(i).value * 100.0,
+                    result.low_equity(i).value * 100.0
+                )
+            } else {
+                String::new()
+            },
+            w = padding
+        );
+    }
+}
+
+/// Prints the equity required to call, the expected value of calling and the verdict
+fn print_decision(pot_odds: PotOdds, equity: Estimate) {
+    let required = pot_odds.required_equity();
+    println!(
+        "\n{:<10}{:.2}% to call {} into {}{}",
+        "required",
+        required * 100.0,
+        pot_odds.to_call,
+        pot_odds.pot,
+        if pot_odds.implied > 0.0 {
+            format!(" with {} implied", pot_odds.implied)
+        } else {
+            String::new()
+        }
+    );
+    println!("{:<10}{:.2}%", "equity", equity.value * 100.0);
+    println!("{:<10}{:+.2}", "call EV", pot_odds.call_ev(equity.value));
+    let (low, high) = equity.confidence_interval();
+    println!(
+        "{:<10}{}{}",
+        "verdict",
+        if pot_odds.should_call(equity.value) {
+            "call"
+        } else {
+            "fold"
+        },
+        if low < required && required < high {
+            ", too close to tell within the 95% CI"
+        } else {
+            ""
+        }
+    );
+}
+
+/// Prints the average, worst and best equity after the runouts of each street,
+/// and how the equities are distributed
+fn print_streets(table: CardSet, streets: &[Vec<(CardSet, f64, f64)>]) {
+    // street `i` deals `i + 1` more cards on the table
+    let street_name = |street: usize| {
+        if table.count_cards() as usize + street + 1 == 4 {
+            "turn"
+        } else {
+            "river"
+        }
+    };
+    println!("\n{:<8}{:>9}   {:<20}best", "street", "average", "worst");
+    for (street, runouts) in streets.iter().enumerate() {
+        let average = weighted_average(runouts.iter().map(|&(_, weight, equity)| (weight, equity)));
+        let by_equity =
+            |a: &&(CardSet, f64, f64), b: &&(CardSet, f64, f64)| a.2.partial_cmp(&b.2).unwrap();
+        let (worst, _, worst_equity) = runouts.iter().min_by(by_equity).unwrap();
+        let (best, _, best_equity) = runouts.iter().max_by(by_equity).unwrap();
+        println!(
+            "{:<8}{:>8.2}%   {:<20}{:.2}% {:?}",
+            street_name(street),
+            average * 100.0,
+            format!("{:.2}% {:?}", worst_equity * 100.0, worst),
+            best_equity * 100.0,
+            best
+        );
+    }
+
+    print!("\n{:<10}", "equity");
+    for street in 0..streets.len() {
+        print!("{:>9}", street_name(street));
+    }
+    println!();
+    for bucket in 0..10 {
+        print!("{:<10}", format!("{}-{}%", bucket * 10, bucket * 10 + 10));
+        for runouts in streets {
+            // probability of the runouts in the bucket
+            let probability = weighted_average(runouts.iter().map(|&(_, weight, equity)| {
+                let in_bucket = ((equity * 10.0) as usize).min(9) == bucket;
+                (weight, if in_bucket { 1.0 } else { 0.0 })
+            }));
+            print!("{:>8.2}%", probability * 100.0);
+        }
+        println!();
+    }
+}
+
+/// Prints our hand and equity after each card that can come next
+fn print_runouts(runouts: &[Runout]) {
+    let exact = runouts
+        .iter()
+        .all(|runout| runout.equity.standard_error == 0.0);
+    println!(
+        "\n{:<6}{:<15}{:>7}{}",
+        "card",
+        "hand",
+        "equity",
+        if exact { "" } else { "     ±95%" }
+    );
+    for runout in runouts {
+        println!(
+            "{:<6}{:<15}{:>6.2}%{}",
+            format!("{:?}", runout.card),
+            runout.combination.name(),
+            runout.equity.value * 100.0,
+            if exact {
+                String::new()
+            } else {
+                format!("   {:>5.2}%", runout.equity.margin() * 100.0)
+            }
+        );
+    }
+}
+
+/// Prints how often our final hand and the winning hand are of each category
+fn print_categories(result: &SimulationResult) {
+    println!("\n{:<15}{:>9}{:>11}", "category", "you", "winner");
+    for &category in CombinationType::ALL.iter() {
+        println!(
+            "{:<15}{:>8.3}%{:>10.3}%",
+            format!("{:?}", category),
+            result.hand_category(category).value * 100.0,
+            result.winning_category(category).value * 100.0
+        );
+    }
+}
+
+/// Prints the outs grouped by category, with the probability of hitting them
+/// among `unseen` cards when `dealt` more are dealt
+fn print_outs(scenario: &Scenario, outs: &[Out]) {
+    let cards = outs
+        .iter()
+        .fold(CardSet::none(), |cards, out| cards | out.card);
+    println!(
+        "\n{:<8}{}/{} cards = {:.2}%",
+        "outs",
+        outs.len(),
+        scenario.deck().count_cards(),
+        hit_probability(scenario, cards) * 100.0
+    );
+    for &category in CombinationType::ALL.iter() {
+        let cards = outs
+            .iter()
+            .filter(|out| out.category == category)
+            .fold(CardSet::none(), |cards, out| cards | out.card);
+        if !cards.is_empty() {
+            println!(
+                "{:<15}{:>3}   {:>6.2}%   {:?}",
+                format!("{:?}", category),
+                cards.count_cards(),
+                hit_probability(scenario, cards) * 100.0,
+                cards
+            );
+        }
+    }
+}
+
+/// Prints standard error and 95% confidence interval of an estimate, unless it is exact
+fn print_uncertainty(estimate: Estimate) {
+    if estimate.standard_error > 0.0 {
+        let (low, high) = estimate.confidence_interval();
+        print!(
+            "   se {:.3}%   95% CI {:.2}% - {:.2}%",
+            estimate.standard_error * 100.0,
+            low * 100.0,
+            high * 100.0
+        );
+    }
+    println!();
+}
+
 #[derive(FromArgs)]
-/// Estimate Poker Texas Holdem winning probabilities simulating games with the cards provided.
+/// Estimate Poker Texas Holdem, Omaha or Stud winning probabilities simulating games with the
+/// cards provided.
 /// Cards are given as a string, for example "4CAQ" means 2 cards: 4 of ♥ and ace of ♦.
 /// The values are '1' or 'A', '2' to '10', 'J' or '11', 'Q' or '12', 'K' or '13'.
 /// Suits are 'C' or '♥', 'Q' or '♦', 'P' or '♠' and 'F' or '♣'.
 /// All values and suits can be also lowercase.
 struct SimulationArgs {
-    /// cards in hand, maximum 2, defaults to no cards
+    /// cards in hand, maximum 2, 4, 5 or 7 depending on the game, defaults to no cards
     #[argh(option, default = "String::new()", short = 'h')]
     hand: String,
 
-    /// cards on the table, maximum 5, defaults to no cards
+    /// cards on the table, maximum 5 and none in stud, defaults to no cards
     #[argh(option, default = "String::new()", short = 't')]
     table: String,
 
-    /// number of players in game, defaults to 4
-    #[argh(option, default = "4", short = 'p')]
-    players: u32,
+    /// cards in hand of an opponent, repeat for each opponent,
+    /// the other opponents get random cards
+    #[argh(option, short = 'o')]
+    opponent: Vec<String>,
+
+    /// range of an opponent, such as "QQ+,AKs,AJo+,76s,22-55,A2s-A5s" or "top 15%",
+    /// repeat for each opponent, not in omaha
+    #[argh(option, short = 'r')]
+    range: Vec<String>,
+
+    /// cards known to be out of the deck, such as folded or exposed ones, defaults to no cards
+    #[argh(option, default = "String::new()", short = 'd')]
+    dead: String,
+
+    /// number of players in game, defaults to 4 or to the number of given hands
+    #[argh(option, short = 'p')]
+    players: Option<u32>,
+
+    /// game played: "holdem", "plo4" for Omaha with 4 cards, "plo5" for Omaha with 5 cards
+    /// "shortdeck" for Holdem without cards from 2 to 5, "stud" for seven card stud,
+    /// or "plo8", "plo5-8" and "stud8" for their hi/lo 8-or-better versions, defaults to holdem
+    #[argh(option, default = "Game::Holdem")]
+    game: Game,
+
+    /// hand evaluator: "table" for precomputed lookup tables or "bits" for bit manipulation,
+    /// both giving the same results, defaults to table
+    #[argh(option, default = "EvaluatorKind::Table")]
+    evaluator: EvaluatorKind,
 
     /// number of rounds to simulate, defaults to 1 million
     #[argh(option, default = "1000000", short = 'g')]
-    games: u32,
+    games: u64,
+
+    /// simulate until the 95% confidence interval of the equity is within the given
+    /// precision, for example "0.1%", instead of a fixed number of games
+    #[argh(option, from_str_fn(parse_precision))]
+    precision: Option<f64>,
+
+    /// simulate for the given time, for example "500ms", "2s" or "1h",
+    /// instead of a fixed number of games, can be combined with precision
+    #[argh(option, from_str_fn(parse_duration))]
+    duration: Option<Duration>,
+
+    /// how to compute probabilities: "exact" enumerates every deal, "montecarlo" simulates the
+    /// given number of games, "auto" enumerates when there are no more deals than games,
+    /// defaults to auto
+    #[argh(option, default = "Engine::Auto", short = 'e')]
+    engine: Engine,
+
+    /// seed of the random games, the same seed always gives the same result,
+    /// defaults to a random seed
+    #[argh(option)]
+    seed: Option<u64>,
 
     /// print provided number of simulated rounds, optional
     #[argh(option, default = "0", short = 's')]
     show: u32,
 
+    /// chips in the pot including the bet to call, to decide whether calling is profitable
+    #[argh(option)]
+    pot: Option<f64>,
+
+    /// chips needed to call, required with pot
+    #[argh(option)]
+    to_call: Option<f64>,
+
+    /// chips expected to be won on later streets when we hit, requires pot and to-call,
+    /// defaults to 0
+    #[argh(option, default = "0.0")]
+    implied: f64,
+
+    /// list the cards that improve the hand to the lead, the table must have 3 or 4 cards
+    #[argh(switch)]
+    outs: bool,
+
+    /// show how the equity changes on the next streets, the table must have 3 or 4 cards
+    #[argh(switch)]
+    streets: bool,
+
+    /// list the equity after each card that can come next, the table must have 3 or 4 cards
+    #[argh(switch)]
+    runouts: bool,
+
     #[argh(switch)]
     /// display execution time
     time: bool,
 }
 
+/// Parses a precision as a percentage such as "0.1%" or a fraction such as "0.001"
+fn parse_precision(value: &str) -> Result<f64, String> {
+    let (number, scale) = match value.trim().strip_suffix('%') {
+        Some(percentage) => (percentage, 0.01),
+        None => (value.trim(), 1.0),
+    };
+    match number.trim().parse::<f64>() {
+        Ok(precision) if precision > 0.0 => Ok(precision * scale),
+        _ => Err(format!("invalid precision '{}'", value)),
+    }
+}
+
+/// Parses a duration such as "500ms", "2s", "1.5m" or "1h", seconds if there is no unit
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let unit_start = value
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(value.len());
+    let seconds = match &value[unit_start..] {
+        "ms" => 0.001,
+        "" | "s" => 1.0,
+        "m" | "min" => 60.0,
+        "h" => 3600.0,
+        _ => return Err(format!("invalid duration unit in '{}'", value)),
+    };
+    match value[..unit_start].trim().parse::<f64>() {
+        Ok(amount) if amount > 0.0 => Ok(Duration::from_secs_f64(amount * seconds)),
+        _ => Err(format!("invalid duration '{}'", value)),
+    }
+}
+
+#[test]
+fn parse_stop_conditions() {
+    assert_eq!(parse_precision("0.1%"), Ok(0.001));
+    assert_eq!(parse_precision("0.002"), Ok(0.002));
+    assert!(parse_precision("-1%").is_err());
+    assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+    assert_eq!(parse_duration("2s"), Ok(Duration::from_secs(2)));
+    assert_eq!(parse_duration("3"), Ok(Duration::from_secs(3)));
+    assert_eq!(parse_duration("1.5m"), Ok(Duration::from_secs(90)));
+    assert_eq!(parse_duration("1h"), Ok(Duration::from_secs(3600)));
+    assert!(parse_duration("2 weeks").is_err());
+}
+
 enum SimulationError {
     HandParseError(CardParseError),
     TableParseError(CardParseError),
-    InvalidHand(CardSet),
-    InvalidTable(CardSet),
-    WrongNumberOfPlayers(u32),
-    InvalidHandTableComposition(CardSet),
+    DeadParseError(CardParseError),
+    OpponentParseError(usize, CardParseError),
+    RangeParseError(usize, RangeParseError),
+    /// error of the scenario along with the number of opponent hands given
+    InvalidScenario(ScenarioError, usize),
+    TooManyOpponents(usize, u32),
+    NotFlopOrTurn(&'static str),
+    InvalidPotOdds,
 }
 
 fn execute() -> Result<(), SimulationError> {
     let args: SimulationArgs = argh::from_env();
 
-    let hand = CardSet::from_str(&args.hand).map_err(|err| SimulationError::HandParseError(err))?;
-    let table =
-        CardSet::from_str(&args.table).map_err(|err| SimulationError::TableParseError(err))?;
-
-    if args.players < 2 || args.players > 8 {
-        Err(SimulationError::WrongNumberOfPlayers(args.players))
-    } else if hand.count_cards() > MAX_HAND {
-        Err(SimulationError::InvalidHand(hand))
-    } else if table.count_cards() > MAX_TABLE {
-        Err(SimulationError::InvalidTable(table))
-    } else if !(table & hand).is_empty() {
-        Err(SimulationError::InvalidHandTableComposition(table & hand))
-    } else {
-        let maybe_timing = if args.time {
-            Some(Instant::now())
-        } else {
-            None
-        };
+    let hand = CardSet::from_str(&args.hand).map_err(SimulationError::HandParseError)?;
+    let table = CardSet::from_str(&args.table).map_err(SimulationError::TableParseError)?;
+    let dead = CardSet::from_str(&args.dead).map_err(SimulationError::DeadParseError)?;
+    let opponents = args
+        .opponent
+        .iter()
+        .enumerate()
+        .map(|(i, cards)| {
+            CardSet::from_str(cards).map_err(|err| SimulationError::OpponentParseError(i, err))
+        })
+        .collect::<Result<Vec<CardSet>, SimulationError>>()?;
+    let ranges = args
+        .range
+        .iter()
+        .enumerate()
+        .map(|(i, range)| {
+            Range::from_str(range).map_err(|err| SimulationError::RangeParseError(i, err))
+        })
+        .collect::<Result<Vec<Range>, SimulationError>>()?;
+    let given = opponents.len() + ranges.len();
+    let players = args
+        .players
+        .unwrap_or(if given == 0 { 4 } else { given as u32 + 1 });
+    let invalid = |error| SimulationError::InvalidScenario(error, opponents.len());
+    // checked before sizing the players from the requested number
+    if !(2..=8).contains(&players) {
+        return Err(invalid(ScenarioError::WrongNumberOfPlayers(
+            players as usize,
+        )));
+    } else if given as u32 >= players {
+        return Err(SimulationError::TooManyOpponents(given, players));
+    }
 
-        if args.show > 0 {
-            print_simulation(hand, table, args.players, args.show);
+    let pot_odds = match (args.pot, args.to_call) {
+        (None, None) if args.implied == 0.0 => None,
+        (Some(pot), Some(to_call)) if pot >= 0.0 && to_call > 0.0 && args.implied >= 0.0 => {
+            Some(PotOdds {
+                pot,
+                to_call,
+                implied: args.implied,
+            })
         }
+        _ => return Err(SimulationError::InvalidPotOdds),
+    };
 
-        let wins = if hand.is_empty() {
-            // without a hand, winning probabilities are equal
-            args.games / args.players
-        } else {
-            simulate(hand, table, args.players, args.games)
-        };
+    // ranges lose the combos blocked by known cards or out of the deck
+    let known = opponents
+        .iter()
+        .fold(table | hand | dead, |known, &opponent| known | opponent);
+    let ranges: Vec<Range> = ranges
+        .iter()
+        .map(|range| range.without(known | !args.game.deck()))
+        .collect();
 
-        let maybe_execution_time = maybe_timing.map(|time| time.elapsed());
-
-        if hand.is_empty() {
-            println!("No hand, equal winning probability among players.");
+    let hand_name = |cards: CardSet| {
+        if cards.is_empty() {
+            "random".to_string()
         } else {
-            println!(
-                "({:?}) ({:?}) = {}",
-                hand,
-                table,
-                (hand | table).comb().name()
-            );
+            format!("{:?}", cards)
         }
+    };
+    let mut names: Vec<String> = Some(hand)
+        .iter()
+        .chain(&opponents)
+        .map(|&cards| hand_name(cards))
+        .collect();
+    names.extend(args.range.iter().cloned());
+    names.resize(players as usize, hand_name(CardSet::none()));
+
+    let mut holdings: Vec<Holding> = Some(hand)
+        .iter()
+        .chain(&opponents)
+        .map(|&cards| Holding::Cards(cards))
+        .collect();
+    holdings.extend(ranges.into_iter().map(Holding::Range));
+    holdings.resize_with(players as usize, || Holding::Cards(CardSet::none()));
+    let scenario = Scenario {
+        game: args.game,
+        evaluator: args.evaluator,
+        table,
+        holdings,
+        dead,
+    };
+    scenario.validate().map_err(invalid)?;
+    if (args.outs || args.streets || args.runouts)
+        && (hand.count_cards() != args.game.hand_cards() || !(3..=4).contains(&table.count_cards()))
+    {
+        return Err(SimulationError::NotFlopOrTurn(if args.outs {
+            "outs"
+        } else if args.streets {
+            "streets"
+        } else {
+            "runouts"
+        }));
+    }
 
+    let maybe_timing = if args.time {
+        Some(Instant::now())
+    } else {
+        None
+    };
+
+    let seed = args.seed.unwrap_or_else(rand::random);
+
+    if args.show > 0 {
+        print_simulation(&scenario, args.show, seed);
+    }
+
+    let exact = args.engine.is_exact(&scenario, args.games);
+    let result = if given == 0 && hand.is_empty() {
+        None
+    } else if exact {
+        Some(enumerate(&scenario).map_err(invalid)?)
+    } else {
+        Some(match Stop::new(args.precision, args.duration) {
+            Some(stop) => simulate_adaptive(&scenario, stop, seed).map_err(invalid)?,
+            None => simulate(&scenario, args.games, seed).map_err(invalid)?,
+        })
+    };
+    // our equity after each runout of one more card, then of two more cards up to the river,
+    // with the weight of each runout
+    let streets: Vec<Vec<(CardSet, f64, f64)>> = if args.streets {
+        (1..=args.game.table_cards() - table.count_cards())
+            .map(|cards| {
+                Ok(
+                    runout_results(&scenario, cards, args.engine, args.games, seed)
+                        .map_err(invalid)?
+                        .into_iter()
+                        .map(|(runout, weight, result)| (runout, weight, result.equity(0).value))
+                        .collect(),
+                )
+            })
+            .collect::<Result<_, SimulationError>>()?
+    } else {
+        Vec::new()
+    };
+    let runouts = if args.runouts {
+        next_card_runouts(&scenario, args.engine, args.games, seed).map_err(invalid)?
+    } else {
+        Vec::new()
+    };
+    let outs = if args.outs {
+        outs(&scenario, args.engine, args.games, seed).map_err(invalid)?
+    } else {
+        Vec::new()
+    };
+
+    let maybe_execution_time = maybe_timing.map(|time| time.elapsed());
+
+    if let Some(result) = result {
         println!(
-            "{}/{} = {:.2}%",
-            wins,
-            args.games,
-            wins as f64 / args.games as f64 * 100.0
+            "({:?}) ({:?}) = {}",
+            hand,
+            table,
+            args.game.evaluate(hand, table).description()
         );
-
-        if let Some(execution_time) = maybe_execution_time {
-            println!("\nsimulated in {:?}", execution_time);
+        if !dead.is_empty() {
+            println!("dead cards ({:?})", dead);
+        }
+        if exact {
+            println!("exact enumeration of every deal");
+        } else {
+            println!("seed {}", seed);
+        }
+        println!();
+        let you = result.players[0];
+        // in hi/lo a half of the pot is won alone as often as shared,
+        // so wins and ties are replaced by the scoops and the equity of each half
+        let counts = if args.game.is_hilo() {
+            vec![("scoop", you.wins, result.scoop(0))]
+        } else {
+            vec![
+                ("wins", you.wins, result.win(0)),
+                ("ties", you.ties, result.tie(0)),
+                ("losses", you.losses, result.loss(0)),
+            ]
+        };
+        for (label, count, estimate) in counts {
+            print!(
+                "{:<8}{}/{} = {:.2}%",
+                label,
+                count,
+                result.games(),
+                estimate.value * 100.0
+            );
+            print_uncertainty(estimate);
+        }
+        let equity = result.equity(0);
+        print!("{:<8}{:.2}%", "equity", equity.value * 100.0);
+        print_uncertainty(equity);
+        if args.game.is_hilo() {
+            for (label, estimate) in [
+                ("high", result.high_equity(0)),
+                ("low", result.low_equity(0)),
+                ("lows", result.low_made()),
+            ]
+            .iter()
+            {
+                print!("{:<8}{:.2}%", label, estimate.value * 100.0);
+                print_uncertainty(*estimate);
+            }
+        }
+        if given > 0 {
+            print_players(&names, &result, args.game.is_hilo());
+        }
+        if let Some(pot_odds) = pot_odds {
+            print_decision(pot_odds, equity);
+        }
+        if args.streets {
+            print_streets(table, &streets);
         }
-        Ok(())
+        if args.runouts {
+            print_runouts(&runouts);
+        }
+        print_categories(&result);
+        if args.outs {
+            print_outs(&scenario, &outs);
+        }
+    } else {
+        // without known cards, winning probabilities are equal
+        println!("No hand, equal winning probability among players.");
+        println!("{:<8}{:.2}%", "equity", 100.0 / players as f64);
+    }
+
+    if let Some(execution_time) = maybe_execution_time {
+        println!("\nsimulated in {:?}", execution_time);
     }
+    Ok(())
 }
 
 fn print_card_parse_error(error: CardParseError, msg: &str) {
@@ -205,28 +718,96 @@ fn print_card_parse_error(error: CardParseError, msg: &str) {
     }
 }
 
+/// Prints `error`, player 0 being our hand, followed by the `opponents` hands then the ranges
+fn print_scenario_error(error: ScenarioError, opponents: usize) {
+    let player = |i: usize| {
+        if i == 0 {
+            "hand".to_string()
+        } else if i <= opponents {
+            format!("opponent {}", i)
+        } else {
+            format!("range {}", i - opponents)
+        }
+    };
+    match error {
+        ScenarioError::InvalidHand(i, hand, max) => println!(
+            "Error invalid hand: {} has {} cards, maximum is {}",
+            player(i),
+            hand.count_cards(),
+            max
+        ),
+        ScenarioError::InvalidTable(table, max) => println!(
+            "Error invalid table: table has {} cards, maximum is {}",
+            table.count_cards(),
+            max
+        ),
+        ScenarioError::InvalidDeadComposition(composition) => println!(
+            "Error: dead cards are sharing the following cards with table: {:?}",
+            composition
+        ),
+        ScenarioError::InvalidHandComposition(i, composition) => println!(
+            "Error: {} is sharing the following cards with table, dead cards or other hands: {:?}",
+            player(i),
+            composition
+        ),
+        ScenarioError::WrongNumberOfPlayers(players) => {
+            println!("Error: required 2-8 players, found {}", players)
+        }
+        ScenarioError::EmptyRange(i) => println!(
+            "Error: every combo of {} uses cards already in the table or other hands",
+            player(i)
+        ),
+        ScenarioError::IncompatibleRanges => {
+            println!("Error: ranges cannot be dealt together without sharing cards")
+        }
+        ScenarioError::RangesNotHoldem => {
+            println!("Error: ranges are only supported in holdem and short deck")
+        }
+        ScenarioError::NotInDeck(cards) => println!(
+            "Error: the following cards are not in the deck of the game: {:?}",
+            cards
+        ),
+        ScenarioError::NotEnoughCards(players) => println!(
+            "Error: not enough cards in the deck to deal {} players",
+            players
+        ),
+    }
+}
+
 fn main() {
     if let Err(error) = execute() {
         match error {
             SimulationError::HandParseError(e) => print_card_parse_error(e, "hand"),
             SimulationError::TableParseError(e) => print_card_parse_error(e, "table"),
-            SimulationError::InvalidHand(hand) => println!(
-                "Error invalid hand: hand has {} cards, maximum is {}",
-                hand.count_cards(),
-                MAX_HAND
+            SimulationError::DeadParseError(e) => print_card_parse_error(e, "dead cards"),
+            SimulationError::OpponentParseError(i, e) => {
+                print_card_parse_error(e, &format!("opponent {}", i + 1))
+            }
+            SimulationError::RangeParseError(i, e) => match e {
+                RangeParseError::InvalidHand(hand) => println!(
+                    "Error parsing range {}: '{}' is not a hand or a range of hands",
+                    i + 1,
+                    hand
+                ),
+                RangeParseError::InvalidPercentage(percentage) => println!(
+                    "Error parsing range {}: '{}' is not a percentage between 0 and 100",
+                    i + 1,
+                    percentage
+                ),
+            },
+            SimulationError::InvalidScenario(e, opponents) => print_scenario_error(e, opponents),
+            SimulationError::InvalidPotOdds => println!(
+                "Error: pot and to-call must be given together, with a positive amount to call, \
+                 and implied only along with them"
             ),
-            SimulationError::InvalidTable(table) => println!(
-                "Error invalid table: table has {} cards, maximum is {}",
-                table.count_cards(),
-                MAX_TABLE
+            SimulationError::NotFlopOrTurn(option) => println!(
+                "Error: {} require a complete hand and 3 or 4 cards on the table",
+                option
             ),
-            SimulationError::InvalidHandTableComposition(composition) => println!(
-                "Error: table and hand are sharing the following cards: {:?}",
-                composition
+            SimulationError::TooManyOpponents(opponents, players) => println!(
+                "Error: {} opponent hands given, but only {} players",
+                opponents, players
             ),
-            SimulationError::WrongNumberOfPlayers(players) => {
-                println!("Error: required 2-8 players, found {}", players)
-            }
         }
     }
 }
@@ -0,0 +1,47 @@
+/// Chips at stake when facing a bet
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PotOdds {
+    /// chips in the pot, including the bet to call
+    pub pot: f64,
+    /// chips needed to call
+    pub to_call: f64,
+    /// chips we expect to win later on when hitting, 0 for plain pot odds
+    pub implied: f64,
+}
+
+impl PotOdds {
+    /// Equity at which calling breaks even
+    pub fn required_equity(&self) -> f64 {
+        self.to_call / (self.pot + self.implied + self.to_call)
+    }
+    /// Chips won on average by calling with `equity`, negative when losing
+    pub fn call_ev(&self, equity: f64) -> f64 {
+        equity * (self.pot + self.implied) - (1.0 - equity) * self.to_call
+    }
+    /// Whether calling with `equity` wins chips on average
+    pub fn should_call(&self, equity: f64) -> bool {
+        self.call_ev(equity) > 0.0
+    }
+}
+
+#[test]
+fn pot_odds() {
+    let odds = PotOdds {
+        pot: 100.0,
+        to_call: 50.0,
+        implied: 0.0,
+    };
+    assert!((odds.required_equity() - 1.0 / 3.0).abs() < 1e-12);
+    assert!(odds.call_ev(1.0 / 3.0).abs() < 1e-12);
+    assert_eq!(odds.call_ev(0.5), 25.0);
+    assert_eq!(odds.call_ev(0.0), -50.0);
+    assert!(odds.should_call(0.4));
+    assert!(!odds.should_call(0.3));
+
+    let implied = PotOdds {
+        implied: 50.0,
+        ..odds
+    };
+    assert_eq!(implied.required_equity(), 0.25);
+    assert!(implied.should_call(0.3));
+}
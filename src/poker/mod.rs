@@ -1,4 +0,0 @@
-pub mod cardset;
-pub mod combination;
-#[cfg(test)]
-mod tests;
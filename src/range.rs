@@ -0,0 +1,192 @@
+use super::cardset::CardSet;
+use rand::Rng;
+use std::str::FromStr;
+
+/// Starting hands sorted by all-in equity against a random hand, used for percentage ranges
+const HAND_RANKING: &str = "\
+    AA KK QQ JJ TT 99 88 AKs 77 AQs AJs AKo ATs AQo AJo KQs \
+    66 A9s ATo KJs A8s KTs KQo A7s A9o KJo 55 QJs K9s A5s A6s A8o \
+    KTo QTs A4s A7o K8s A3s QJo K9o A6o Q9s A5o JTs K7s A2s QTo 44 \
+    A4o K6s Q8s K8o A3o K5s J9s Q9o JTo K7o A2o K4s Q7s K6o T9s J8s \
+    K3s 33 Q8o Q6s K5o J9o K2s Q5s J7s T8s K4o Q4s Q7o T9o J8o K3o \
+    Q6o Q3s 98s T7s J6s K2o 22 Q2s Q5o J5s T8o J7o Q4o 97s J4s T6s \
+    J3s Q3o 98o 87s T7o J6o J2s 96s T5s Q2o J5o T4s 97o 86s J4o T6o \
+    95s T3s 76s J3o 87o T2s 85s 96o J2o T5o 94s 75s T4o 93s 86o 65s \
+    84s 95o T3o 92s 76o 74s T2o 54s 85o 64s 83s 94o 75o 82s 93o 73s \
+    65o 53s 63s 84o 92o 43s 74o 72s 54o 64o 52s 62s 83o 42s 82o 73o \
+    53o 63o 32s 43o 72o 52o 62o 42o 32o";
+
+/// Hole cards a player can hold, written in the usual range notation:
+/// "QQ+,AKs,AJo+,76s,22-55,A2s-A5s,AQKQ" or "top 15%".
+/// Every combo is equally likely, so a hand such as AK weighs 16 combos, AKs 4 and QQ 6.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Range(Vec<CardSet>);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RangeParseError {
+    InvalidHand(String),
+    InvalidPercentage(String),
+}
+
+/// Starting hand such as QQ, AKs, AKo or AK (suited or not)
+#[derive(Clone, Copy)]
+struct StartingHand {
+    high: u32,
+    low: u32,
+    suited: Option<bool>,
+}
+
+impl StartingHand {
+    fn combos(self) -> impl Iterator<Item = CardSet> {
+        (0..4)
+            .flat_map(|s1| (0..4).map(move |s2| (s1, s2)))
+            .filter(move |&(s1, s2)| {
+                if self.high == self.low {
+                    s1 < s2
+                } else {
+                    self.suited.is_none_or(|suited| suited == (s1 == s2))
+                }
+            })
+            .map(move |(s1, s2)| {
+                CardSet::one(self.high + s1 * 13) | CardSet::one(self.low + s2 * 13)
+            })
+    }
+}
+
+/// Rank from 0 (two) to 12 (ace)
+fn parse_rank(c: char) -> Option<u32> {
+    "23456789TJQKA"
+        .find(c.to_ascii_uppercase())
+        .map(|rank| rank as u32)
+}
+
+/// Parses a starting hand at the start of `s`, returning it with the rest of the input
+fn parse_starting_hand(s: &str) -> Option<(StartingHand, &str)> {
+    let mut chars = s.chars();
+    let first = parse_rank(chars.next()?)?;
+    let second = parse_rank(chars.next()?)?;
+    let rest = chars.as_str();
+    let (suited, rest) = match rest.chars().next().map(|c| c.to_ascii_lowercase()) {
+        Some('s') => (Some(true), &rest[1..]),
+        Some('o') => (Some(false), &rest[1..]),
+        _ => (None, rest),
+    };
+    if first == second && suited.is_some() {
+        return None;
+    }
+    let hand = StartingHand {
+        high: first.max(second),
+        low: first.min(second),
+        suited,
+    };
+    Some((hand, rest))
+}
+
+/// Expands notations as QQ, QQ+, 22-55, AKs, AJo+ or A2s-A5s into starting hands
+fn parse_starting_hands(token: &str) -> Option<Vec<StartingHand>> {
+    let (hand, rest) = parse_starting_hand(token)?;
+    let is_pair = hand.high == hand.low;
+    let (from, to) = if rest.is_empty() {
+        (hand.low, hand.low)
+    } else if rest == "+" {
+        // pairs go up to aces, other hands raise the kicker up to the highest card
+        (hand.low, if is_pair { 12 } else { hand.high - 1 })
+    } else if let Some(last) = rest.strip_prefix('-') {
+        let (last, rest) = parse_starting_hand(last)?;
+        let same_shape = if is_pair {
+            last.high == last.low
+        } else {
+            last.high == hand.high && last.suited == hand.suited
+        };
+        if !rest.is_empty() || !same_shape {
+            return None;
+        }
+        (hand.low.min(last.low), hand.low.max(last.low))
+    } else {
+        return None;
+    };
+    Some(
+        (from..=to)
+            .map(|low| StartingHand {
+                high: if is_pair { low } else { hand.high },
+                low,
+                suited: hand.suited,
+            })
+            .collect(),
+    )
+}
+
+impl Range {
+    pub fn combos(&self) -> &[CardSet] {
+        &self.0
+    }
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// Range without the combos holding any of `cards`
+    pub fn without(&self, cards: CardSet) -> Range {
+        Range(
+            self.0
+                .iter()
+                .copied()
+                .filter(|&combo| (combo & cards).is_empty())
+                .collect(),
+        )
+    }
+    /// Picks one of the combos at random
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CardSet {
+        self.0[rng.gen_range(0..self.0.len())]
+    }
+    /// Best starting hands, in order of equity, adding up to `percentage` of all the combos
+    pub fn top(percentage: f64) -> Range {
+        let wanted = (percentage / 100.0 * 1326.0).round() as usize;
+        let mut combos = Vec::with_capacity(wanted);
+        for hand in HAND_RANKING.split(' ') {
+            if combos.len() >= wanted {
+                break;
+            }
+            combos.extend(parse_starting_hand(hand).unwrap().0.combos());
+        }
+        Range(combos)
+    }
+}
+
+impl FromStr for Range {
+    type Err = RangeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let lower = s.to_ascii_lowercase();
+        if lower.starts_with("top") || lower.ends_with('%') {
+            let number = lower.trim_start_matches("top").trim_end_matches('%').trim();
+            return match number.parse::<f64>() {
+                Ok(percentage) if percentage > 0.0 && percentage <= 100.0 => {
+                    Ok(Range::top(percentage))
+                }
+                _ => Err(RangeParseError::InvalidPercentage(s.to_string())),
+            };
+        }
+        let mut combos: Vec<CardSet> = Vec::new();
+        for token in s.split(',').map(str::trim) {
+            let token_combos: Vec<CardSet> =
+                if let Some(hands) = parse_starting_hands(&token.replace("10", "T")) {
+                    hands.into_iter().flat_map(StartingHand::combos).collect()
+                } else {
+                    // a single combo with explicit suits
+                    match CardSet::from_str(token) {
+                        Ok(cards) if cards.count_cards() == 2 => vec![cards],
+                        _ => return Err(RangeParseError::InvalidHand(token.to_string())),
+                    }
+                };
+            for combo in token_combos {
+                if !combos.contains(&combo) {
+                    combos.push(combo);
+                }
+            }
+        }
+        Ok(Range(combos))
+    }
+}
@@ -0,0 +1,1329 @@
+use super::cardset::CardSet;
+use super::combination::{Combination, CombinationType};
+use super::evaluator::EvaluatorKind;
+use super::game::Game;
+use super::low::Low;
+use super::range::Range;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rayon::prelude::*;
+use std::{
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+/// Units a pot is divided into, so that any split of the pot or of half of it
+/// among up to 8 players is exact
+pub const POT_SHARES: u64 = 1680;
+
+/// What is known of the cards of a player
+#[derive(Clone, Debug)]
+pub enum Holding {
+    /// known cards, the missing ones are dealt at random
+    Cards(CardSet),
+    /// any combo of the range, none of them sharing cards with the rest of the scenario
+    Range(Range),
+}
+
+impl Holding {
+    pub fn known_cards(&self) -> CardSet {
+        match self {
+            Holding::Cards(cards) => *cards,
+            Holding::Range(_) => CardSet::none(),
+        }
+    }
+}
+
+/// Why a scenario cannot be played, players being numbered from 0, us
+#[derive(Debug, PartialEq, Eq)]
+pub enum ScenarioError {
+    /// the game is played by 2 to 8 players
+    WrongNumberOfPlayers(usize),
+    /// the table has more cards than the maximum of the game
+    InvalidTable(CardSet, u32),
+    /// the player has more cards than the maximum of the game
+    InvalidHand(usize, CardSet, u32),
+    /// ranges are two-card hands, so only supported when hands have two cards
+    RangesNotHoldem,
+    /// the deck cannot complete the table and every hand, dead cards left aside
+    NotEnoughCards(usize),
+    /// cards that are not in the deck of the game
+    NotInDeck(CardSet),
+    /// cards both on the table and dead
+    InvalidDeadComposition(CardSet),
+    /// cards of the player also on the table, dead or held by a previous player
+    InvalidHandComposition(usize, CardSet),
+    /// every combo of the range of the player uses known cards
+    EmptyRange(usize),
+    /// the ranges cannot be dealt together without sharing cards
+    IncompatibleRanges,
+}
+
+/// Cards dealt in one step of the enumeration
+enum Step<'a> {
+    /// every combination of the given number of cards
+    Draw(u32),
+    /// every combo of the range
+    Range(&'a Range),
+}
+
+impl<'a> Step<'a> {
+    /// All the ways to deal this step from `deck`
+    fn choices(&self, deck: CardSet) -> Vec<CardSet> {
+        match self {
+            Step::Draw(count) => deck.subsets(*count).collect(),
+            Step::Range(range) => range
+                .combos()
+                .iter()
+                .copied()
+                .filter(|&combo| combo & deck == combo)
+                .collect(),
+        }
+    }
+}
+
+/// Cards known in a game, player 0 is us
+#[derive(Clone, Debug)]
+pub struct Scenario {
+    pub game: Game,
+    pub evaluator: EvaluatorKind,
+    pub table: CardSet,
+    pub holdings: Vec<Holding>,
+    /// cards out of the deck, such as folded or exposed ones
+    pub dead: CardSet,
+}
+
+impl Scenario {
+    pub fn players(&self) -> usize {
+        self.holdings.len()
+    }
+    /// Cards that can still be dealt
+    pub fn deck(&self) -> CardSet {
+        self.game.deck()
+            & !self
+                .holdings
+                .iter()
+                .fold(self.table | self.dead, |known, holding| {
+                    known | holding.known_cards()
+                })
+    }
+    /// Checks that the scenario can be played, as required by every simulation
+    pub fn validate(&self) -> Result<(), ScenarioError> {
+        let (game, players) = (self.game, self.players());
+        if !(2..=8).contains(&players) {
+            return Err(ScenarioError::WrongNumberOfPlayers(players));
+        } else if self.table.count_cards() > game.table_cards() {
+            return Err(ScenarioError::InvalidTable(self.table, game.table_cards()));
+        }
+        for (i, holding) in self.holdings.iter().enumerate() {
+            let cards = holding.known_cards();
+            if cards.count_cards() > game.hand_cards() {
+                return Err(ScenarioError::InvalidHand(i, cards, game.hand_cards()));
+            }
+        }
+        let ranges: Vec<(usize, &Range)> = self
+            .holdings
+            .iter()
+            .enumerate()
+            .filter_map(|(i, holding)| match holding {
+                Holding::Cards(_) => None,
+                Holding::Range(range) => Some((i, range)),
+            })
+            .collect();
+        if !ranges.is_empty() && game.hand_cards() != 2 {
+            return Err(ScenarioError::RangesNotHoldem);
+        } else if game.table_cards() + players as u32 * game.hand_cards() + self.dead.count_cards()
+            > game.deck().count_cards()
+        {
+            return Err(ScenarioError::NotEnoughCards(players));
+        }
+
+        let known = self
+            .holdings
+            .iter()
+            .fold(self.table | self.dead, |known, holding| {
+                known | holding.known_cards()
+            });
+        if !(known & !game.deck()).is_empty() {
+            return Err(ScenarioError::NotInDeck(known & !game.deck()));
+        } else if !(self.table & self.dead).is_empty() {
+            return Err(ScenarioError::InvalidDeadComposition(
+                self.table & self.dead,
+            ));
+        }
+        let mut taken = self.table | self.dead;
+        for (i, holding) in self.holdings.iter().enumerate() {
+            let cards = holding.known_cards();
+            if !(taken & cards).is_empty() {
+                return Err(ScenarioError::InvalidHandComposition(i, taken & cards));
+            }
+            taken |= cards;
+        }
+
+        let blocked = taken | !game.deck();
+        if let Some(&(i, _)) = ranges
+            .iter()
+            .find(|(_, range)| range.without(blocked).is_empty())
+        {
+            return Err(ScenarioError::EmptyRange(i));
+        }
+        let ranges: Vec<&Range> = ranges.into_iter().map(|(_, range)| range).collect();
+        if !Scenario::ranges_compatible(&ranges, blocked) {
+            return Err(ScenarioError::IncompatibleRanges);
+        }
+        Ok(())
+    }
+    /// Steps to deal every player a complete hand: the table runout, then each player
+    fn steps(&self) -> Vec<Step<'_>> {
+        let mut steps = vec![Step::Draw(
+            self.game.table_cards() - self.table.count_cards(),
+        )];
+        steps.extend(self.holdings.iter().map(|holding| match holding {
+            Holding::Cards(cards) => Step::Draw(self.game.hand_cards() - cards.count_cards()),
+            Holding::Range(range) => Step::Range(range),
+        }));
+        steps
+    }
+    /// Number of distinct deals `enumerate` walks through,
+    /// overestimated when ranges share cards among themselves
+    pub fn count_deals(&self) -> f64 {
+        let mut deck_size = self.deck().count_cards();
+        self.steps()
+            .into_iter()
+            .map(|step| match step {
+                Step::Draw(count) => {
+                    let ways = binomial(deck_size, count);
+                    deck_size -= count;
+                    ways
+                }
+                Step::Range(range) => {
+                    deck_size -= self.game.hand_cards();
+                    range.len() as f64
+                }
+            })
+            .product()
+    }
+    /// Whether the ranges can all be dealt together, `used` being the cards already taken
+    pub fn ranges_compatible(ranges: &[&Range], used: CardSet) -> bool {
+        match ranges.split_first() {
+            None => true,
+            Some((range, rest)) => range.combos().iter().any(|&combo| {
+                (combo & used).is_empty() && Scenario::ranges_compatible(rest, used | combo)
+            }),
+        }
+    }
+    /// Number of ways of dealing `ranges` all together, `used` being the cards already taken
+    pub fn count_range_deals(ranges: &[&Range], used: CardSet) -> f64 {
+        match ranges.split_first() {
+            None => 1.0,
+            Some((range, rest)) => range
+                .combos()
+                .iter()
+                .filter(|&&combo| (combo & used).is_empty())
+                .map(|&combo| Scenario::count_range_deals(rest, used | combo))
+                .sum(),
+        }
+    }
+    /// Number of ways of dealing the ranges along with `runout` on the table, so that
+    /// runouts taking cards the ranges need are less likely than the others
+    pub fn runout_weight(&self, runout: CardSet) -> f64 {
+        let ranges: Vec<&Range> = self
+            .holdings
+            .iter()
+            .filter_map(|holding| match holding {
+                Holding::Cards(_) => None,
+                Holding::Range(range) => Some(range),
+            })
+            .collect();
+        Scenario::count_range_deals(&ranges, !self.deck() | runout)
+    }
+    /// Same scenario after `cards` are dealt on the table,
+    /// none if the cards leave the ranges without combos to deal
+    pub fn with_table_cards(&self, cards: CardSet) -> Option<Scenario> {
+        let holdings: Vec<Holding> = self
+            .holdings
+            .iter()
+            .map(|holding| match holding {
+                Holding::Cards(_) => holding.clone(),
+                Holding::Range(range) => Holding::Range(range.without(cards)),
+            })
+            .collect();
+        let ranges: Vec<&Range> = holdings
+            .iter()
+            .filter_map(|holding| match holding {
+                Holding::Cards(_) => None,
+                Holding::Range(range) => Some(range),
+            })
+            .collect();
+        let known = holdings
+            .iter()
+            .fold(self.table | self.dead | cards, |known, holding| {
+                known | holding.known_cards()
+            });
+        if !Scenario::ranges_compatible(&ranges, known) {
+            return None;
+        }
+        Some(Scenario {
+            game: self.game,
+            evaluator: self.evaluator,
+            table: self.table | cards,
+            holdings,
+            dead: self.dead,
+        })
+    }
+    /// Replaces `combs` and `lows` with the combination and the low of each of the complete
+    /// `hands`, leaving `lows` empty unless the game is hi/lo
+    fn evaluate(
+        &self,
+        hands: &[CardSet],
+        table: CardSet,
+        combs: &mut Vec<Combination>,
+        lows: &mut Vec<Option<Low>>,
+    ) {
+        combs.clear();
+        combs.extend(
+            hands
+                .iter()
+                .map(|&hand| self.game.evaluate_with(self.evaluator, hand, table)),
+        );
+        lows.clear();
+        if self.game.is_hilo() {
+            lows.extend(hands.iter().map(|&hand| self.game.low(hand, table)));
+        }
+    }
+    /// Deals the missing cards at random from `deck`, returning the complete table
+    /// and pushing the complete hand of every player into `hands`
+    pub fn deal(
+        &self,
+        rng: &mut impl Rng,
+        deck: &mut CardSet,
+        hands: &mut Vec<CardSet>,
+    ) -> CardSet {
+        // ranges are dealt first and all together again when sharing a card,
+        // so that every compatible combination of their combos is equally likely,
+        // combos holding cards out of the deck being dealt again
+        let mut dealt = CardSet::none();
+        while hands.len() < self.holdings.len() {
+            let cards = match &self.holdings[hands.len()] {
+                Holding::Cards(_) => CardSet::none(),
+                Holding::Range(range) => range.sample(rng),
+            };
+            if !(cards & !*deck).is_empty() {
+                continue;
+            }
+            if (dealt & cards).is_empty() {
+                dealt |= cards;
+                hands.push(cards);
+            } else {
+                dealt = CardSet::none();
+                hands.clear();
+            }
+        }
+        *deck = *deck & !dealt;
+        let table = self.table | deck.draw(self.game.table_cards() - self.table.count_cards(), rng);
+        for (hand, holding) in hands.iter_mut().zip(&self.holdings) {
+            if let Holding::Cards(cards) = holding {
+                *hand = *cards | deck.draw(self.game.hand_cards() - cards.count_cards(), rng);
+            }
+        }
+        table
+    }
+}
+
+/// Holdem scenario with the cards of `table` and the known cards of each of `hands`
+#[cfg(test)]
+fn holdem(table: &str, hands: &[&str]) -> Scenario {
+    Scenario {
+        game: Game::Holdem,
+        evaluator: EvaluatorKind::Table,
+        table: CardSet::from_str(table).unwrap(),
+        holdings: hands
+            .iter()
+            .map(|hand| Holding::Cards(CardSet::from_str(hand).unwrap()))
+            .collect(),
+        dead: CardSet::none(),
+    }
+}
+
+#[test]
+fn validate_scenarios() {
+    let scenario = Scenario {
+        holdings: vec![
+            Holding::Cards(CardSet::from_str("AQAC").unwrap()),
+            Holding::Range(Range::from_str("AA,KK").unwrap()),
+        ],
+        ..holdem("2C7P9F", &[])
+    };
+    assert_eq!(scenario.validate(), Ok(()));
+
+    let players = |count| holdem("2C7P9F", &vec![""; count]);
+    assert_eq!(
+        players(9).validate(),
+        Err(ScenarioError::WrongNumberOfPlayers(9))
+    );
+    assert!(simulate(&players(9), 1_000, 0).is_err());
+    assert!(enumerate(&players(1)).is_err());
+
+    let overlapping = holdem("2C7P9F", &["AQAC", "ACKC"]);
+    assert_eq!(
+        overlapping.validate(),
+        Err(ScenarioError::InvalidHandComposition(
+            1,
+            CardSet::from_str("AC").unwrap()
+        ))
+    );
+    let dead = Scenario {
+        dead: CardSet::from_str("9F").unwrap(),
+        ..scenario.clone()
+    };
+    assert_eq!(
+        dead.validate(),
+        Err(ScenarioError::InvalidDeadComposition(
+            CardSet::from_str("9F").unwrap()
+        ))
+    );
+
+    // our aces leave a single combo to a range of aces, which a dead ace blocks
+    let ranges = Scenario {
+        holdings: vec![
+            Holding::Cards(CardSet::from_str("AQAC").unwrap()),
+            Holding::Range(Range::from_str("AA").unwrap()),
+            Holding::Range(Range::from_str("QQ").unwrap()),
+        ],
+        ..scenario.clone()
+    };
+    assert_eq!(ranges.validate(), Ok(()));
+    let blocked = Scenario {
+        dead: CardSet::from_str("AP").unwrap(),
+        ..ranges.clone()
+    };
+    assert_eq!(blocked.validate(), Err(ScenarioError::EmptyRange(1)));
+    let incompatible = Scenario {
+        holdings: vec![
+            Holding::Cards(CardSet::from_str("AQAC").unwrap()),
+            Holding::Range(Range::from_str("AA").unwrap()),
+            Holding::Range(Range::from_str("AA").unwrap()),
+        ],
+        ..scenario.clone()
+    };
+    assert_eq!(
+        incompatible.validate(),
+        Err(ScenarioError::IncompatibleRanges)
+    );
+    let omaha = Scenario {
+        game: Game::Omaha4,
+        ..scenario
+    };
+    assert_eq!(omaha.validate(), Err(ScenarioError::RangesNotHoldem));
+}
+
+/// Standard normal quantile of a two-sided 95% confidence interval
+const Z_95: f64 = 1.959964;
+
+/// Value computed from the games played along with its standard error, which is 0 when exact
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Estimate {
+    pub value: f64,
+    pub standard_error: f64,
+}
+
+impl Estimate {
+    /// Half width of the 95% confidence interval
+    pub fn margin(&self) -> f64 {
+        Z_95 * self.standard_error
+    }
+    /// 95% confidence interval with the normal approximation, clamped to 0..1
+    pub fn confidence_interval(&self) -> (f64, f64) {
+        (
+            (self.value - self.margin()).max(0.0),
+            (self.value + self.margin()).min(1.0),
+        )
+    }
+    /// Whether the difference from an independent estimate is significant at the 95% level
+    pub fn differs_from(&self, other: &Estimate) -> bool {
+        let standard_error = self.standard_error.hypot(other.standard_error);
+        (self.value - other.value).abs() > Z_95 * standard_error
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct PlayerResult {
+    pub games: u64,
+    /// games in which the whole pot was won, the scoops in hi/lo games
+    pub wins: u64,
+    /// games in which part of the pot was won, in hi/lo games also a half won alone
+    pub ties: u64,
+    pub losses: u64,
+    /// pot won over all games, in `POT_SHARES` units per game
+    pub pot_shares: u64,
+    /// sum of the squared pot won in each game, for the variance of the equity
+    pub pot_shares_squared: u64,
+    /// high hand awards won, in `POT_SHARES` units per award, in hi/lo games
+    pub high_shares: u64,
+    /// low hand awards won, in `POT_SHARES` units per award, in hi/lo games
+    pub low_shares: u64,
+}
+
+impl PlayerResult {
+    /// Records a game in which `shares` of the pot were won
+    fn record(&mut self, shares: u64) {
+        self.games += 1;
+        if shares == POT_SHARES {
+            self.wins += 1;
+        } else if shares > 0 {
+            self.ties += 1;
+        } else {
+            self.losses += 1;
+        }
+        self.pot_shares += shares;
+        self.pot_shares_squared += shares.pow(2);
+    }
+    fn merge(self, other: PlayerResult) -> PlayerResult {
+        PlayerResult {
+            games: self.games + other.games,
+            wins: self.wins + other.wins,
+            ties: self.ties + other.ties,
+            losses: self.losses + other.losses,
+            pot_shares: self.pot_shares + other.pot_shares,
+            pot_shares_squared: self.pot_shares_squared + other.pot_shares_squared,
+            high_shares: self.high_shares + other.high_shares,
+            low_shares: self.low_shares + other.low_shares,
+        }
+    }
+    /// Average fraction of the pot won per game, ties split equally among winners
+    pub fn equity(&self) -> f64 {
+        self.pot_shares as f64 / (self.games * POT_SHARES) as f64
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SimulationResult {
+    /// results of each player, us first
+    pub players: Vec<PlayerResult>,
+    /// whether every possible deal was played, instead of random ones
+    pub exact: bool,
+    /// how many times our final hand was of each category, indexed as `CombinationType::ALL`
+    pub categories: [u64; 10],
+    /// how many times the winning hand was of each category
+    pub winning_categories: [u64; 10],
+    /// how many times a low qualified for half of the pot, in hi/lo games
+    pub lows: u64,
+}
+
+impl SimulationResult {
+    fn new(players: usize) -> SimulationResult {
+        SimulationResult {
+            players: vec![PlayerResult::default(); players],
+            exact: false,
+            categories: [0; 10],
+            winning_categories: [0; 10],
+            lows: 0,
+        }
+    }
+    pub fn games(&self) -> u64 {
+        self.players[0].games
+    }
+    fn estimate(&self, value: f64, variance: f64) -> Estimate {
+        Estimate {
+            value,
+            standard_error: if self.exact {
+                0.0
+            } else {
+                (variance.max(0.0) / self.games() as f64).sqrt()
+            },
+        }
+    }
+    /// Probability of an outcome counted `count` times
+    fn probability(&self, count: u64) -> Estimate {
+        let p = count as f64 / self.games() as f64;
+        self.estimate(p, p * (1.0 - p))
+    }
+    /// Probability of `player` winning the whole pot
+    pub fn win(&self, player: usize) -> Estimate {
+        self.probability(self.players[player].wins)
+    }
+    /// Probability of `player` splitting the pot
+    pub fn tie(&self, player: usize) -> Estimate {
+        self.probability(self.players[player].ties)
+    }
+    pub fn loss(&self, player: usize) -> Estimate {
+        self.probability(self.players[player].losses)
+    }
+    /// Probability of `player` winning the whole pot of a hi/lo game, the same as `win`
+    pub fn scoop(&self, player: usize) -> Estimate {
+        self.win(player)
+    }
+    /// Average fraction of the high hand award won by `player`, which is the whole pot
+    /// when no low qualifies
+    pub fn high_equity(&self, player: usize) -> Estimate {
+        self.award_equity(self.players[player].high_shares)
+    }
+    /// Average fraction of the low hand award won by `player`, 0 when no low qualifies
+    pub fn low_equity(&self, player: usize) -> Estimate {
+        self.award_equity(self.players[player].low_shares)
+    }
+    /// Average fraction of an award won in the games, with the variance of a win or loss
+    /// bounding the variance of a fraction of the award
+    fn award_equity(&self, shares: u64) -> Estimate {
+        let equity = shares as f64 / (self.games() * POT_SHARES) as f64;
+        self.estimate(equity, equity * (1.0 - equity))
+    }
+    /// Probability of a low qualifying for half of the pot
+    pub fn low_made(&self) -> Estimate {
+        self.probability(self.lows)
+    }
+    /// Probability of our final hand being of `category`
+    pub fn hand_category(&self, category: CombinationType) -> Estimate {
+        self.probability(self.categories[category.index()])
+    }
+    /// Probability of the winning hand being of `category`
+    pub fn winning_category(&self, category: CombinationType) -> Estimate {
+        self.probability(self.winning_categories[category.index()])
+    }
+    /// Average fraction of the pot won by `player`
+    pub fn equity(&self, player: usize) -> Estimate {
+        let result = &self.players[player];
+        let equity = result.equity();
+        let mean_square =
+            result.pot_shares_squared as f64 / (result.games * POT_SHARES.pow(2)) as f64;
+        self.estimate(equity, mean_square - equity * equity)
+    }
+    /// Records a showdown among the final combinations of every player
+    /// and their lows, which are empty unless the game is hi/lo
+    fn record_showdown(&mut self, game: Game, combs: &[Combination], lows: &[Option<Low>]) {
+        let best = *combs
+            .iter()
+            .max_by_key(|&&comb| game.strength(comb))
+            .unwrap();
+        let winners = combs.iter().filter(|&&comb| comb == best).count() as u64;
+        self.categories[combs[0].category().index()] += 1;
+        self.winning_categories[best.category().index()] += 1;
+        let best_low = lows.iter().flatten().max().copied();
+        let low_winners = lows
+            .iter()
+            .filter(|&&low| low.is_some() && low == best_low)
+            .count() as u64;
+        // the high hand gets half of the pot when a low qualifies for the other half
+        let high_pot = if best_low.is_some() {
+            self.lows += 1;
+            POT_SHARES / 2
+        } else {
+            POT_SHARES
+        };
+        for (i, (player, &comb)) in self.players.iter_mut().zip(combs).enumerate() {
+            let mut shares = 0;
+            if comb == best {
+                shares += high_pot / winners;
+                player.high_shares += POT_SHARES / winners;
+            }
+            if best_low.is_some() && lows[i] == best_low {
+                shares += POT_SHARES / 2 / low_winners;
+                player.low_shares += POT_SHARES / low_winners;
+            }
+            player.record(shares);
+        }
+    }
+    fn merge(self, other: SimulationResult) -> SimulationResult {
+        SimulationResult {
+            players: self
+                .players
+                .into_iter()
+                .zip(other.players)
+                .map(|(player, other)| player.merge(other))
+                .collect(),
+            exact: self.exact && other.exact,
+            categories: add_counts(self.categories, other.categories),
+            winning_categories: add_counts(self.winning_categories, other.winning_categories),
+            lows: self.lows + other.lows,
+        }
+    }
+}
+
+/// Games simulated with the same random generator
+pub const CHUNK_GAMES: u64 = 10_000;
+/// Chunks simulated between two checks of an adaptive simulation
+const BATCH_CHUNKS: u64 = 10;
+
+/// Random generator for the chunk `index` of a simulation, independent from every other chunk
+fn chunk_rng(seed: u64, index: u64) -> StdRng {
+    let mut key = [0; 32];
+    key[..8].copy_from_slice(&seed.to_le_bytes());
+    key[8..16].copy_from_slice(&index.to_le_bytes());
+    StdRng::from_seed(key)
+}
+
+/// Simulates the given chunks of a simulation of `games` games,
+/// chunk `i` playing the games from `i * CHUNK_GAMES` up to the next chunk
+fn simulate_chunks(
+    scenario: &Scenario,
+    chunks: std::ops::Range<u64>,
+    games: u64,
+    seed: u64,
+) -> SimulationResult {
+    let players = scenario.players();
+    let deck = scenario.deck();
+    chunks
+        .into_par_iter()
+        .map(|chunk| {
+            let rng = &mut chunk_rng(seed, chunk);
+            let mut result = SimulationResult::new(players);
+            let mut hands = Vec::with_capacity(players);
+            let mut combs = Vec::with_capacity(players);
+            let mut lows = Vec::with_capacity(players);
+            for _ in chunk * CHUNK_GAMES..games.min((chunk + 1) * CHUNK_GAMES) {
+                hands.clear();
+                let mut deck = deck;
+                let table = scenario.deal(rng, &mut deck, &mut hands);
+                scenario.evaluate(&hands, table, &mut combs, &mut lows);
+                result.record_showdown(scenario.game, &combs, &lows);
+            }
+            result
+        })
+        .reduce(|| SimulationResult::new(players), SimulationResult::merge)
+}
+
+fn add_counts(mut a: [u64; 10], b: [u64; 10]) -> [u64; 10] {
+    for (a, b) in a.iter_mut().zip(b) {
+        *a += b;
+    }
+    a
+}
+
+/// Simulates `games` random games, the same seed always giving the same result
+pub fn simulate(
+    scenario: &Scenario,
+    games: u64,
+    seed: u64,
+) -> Result<SimulationResult, ScenarioError> {
+    scenario.validate()?;
+    Ok(simulate_chunks(
+        scenario,
+        0..games.div_ceil(CHUNK_GAMES),
+        games,
+        seed,
+    ))
+}
+
+/// When an adaptive simulation stops
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stop {
+    /// once the 95% confidence interval of our equity is within the precision of the estimate
+    Precision(f64),
+    /// once the duration is elapsed
+    Duration(Duration),
+    /// at the first of the two
+    PrecisionOrDuration(f64, Duration),
+}
+
+impl Stop {
+    /// Rule stopping at the first of the given targets, none without any target
+    pub fn new(precision: Option<f64>, duration: Option<Duration>) -> Option<Stop> {
+        match (precision, duration) {
+            (None, None) => None,
+            (Some(precision), None) => Some(Stop::Precision(precision)),
+            (None, Some(duration)) => Some(Stop::Duration(duration)),
+            (Some(precision), Some(duration)) => {
+                Some(Stop::PrecisionOrDuration(precision, duration))
+            }
+        }
+    }
+    fn precision(self) -> Option<f64> {
+        match self {
+            Stop::Precision(precision) | Stop::PrecisionOrDuration(precision, _) => Some(precision),
+            Stop::Duration(_) => None,
+        }
+    }
+    fn duration(self) -> Option<Duration> {
+        match self {
+            Stop::Duration(duration) | Stop::PrecisionOrDuration(_, duration) => Some(duration),
+            Stop::Precision(_) => None,
+        }
+    }
+}
+
+/// Simulates batches of games until `stop` is reached
+pub fn simulate_adaptive(
+    scenario: &Scenario,
+    stop: Stop,
+    seed: u64,
+) -> Result<SimulationResult, ScenarioError> {
+    scenario.validate()?;
+    let start = Instant::now();
+    let mut result = SimulationResult::new(scenario.players());
+    for batch in 0.. {
+        let chunks = batch * BATCH_CHUNKS..(batch + 1) * BATCH_CHUNKS;
+        let games = chunks.end * CHUNK_GAMES;
+        result = result.merge(simulate_chunks(scenario, chunks, games, seed));
+        let precise = stop
+            .precision()
+            .is_some_and(|precision| result.equity(0).margin() <= precision);
+        let timed_out = stop
+            .duration()
+            .is_some_and(|duration| start.elapsed() >= duration);
+        if precise || timed_out {
+            break;
+        }
+    }
+    Ok(result)
+}
+
+#[test]
+fn simulate_seeded() {
+    let scenario = Scenario {
+        holdings: vec![
+            Holding::Cards(CardSet::from_str("AQAC").unwrap()),
+            Holding::Range(Range::from_str("AA,KK,99").unwrap()),
+            Holding::Cards(CardSet::none()),
+        ],
+        ..holdem("2C7P9F", &[])
+    };
+    let result = simulate(&scenario, 25_000, 42).unwrap();
+    assert_eq!(result.games(), 25_000);
+    let same = simulate(&scenario, 25_000, 42).unwrap();
+    for (player, other) in result.players.iter().zip(&same.players) {
+        assert_eq!(player.wins, other.wins);
+        assert_eq!(player.ties, other.ties);
+        assert_eq!(player.pot_shares, other.pot_shares);
+    }
+    let equity = result.equity(0);
+    assert!(equity.standard_error > 0.0 && equity.margin() < 0.01);
+    let (low, high) = equity.confidence_interval();
+    assert!(low < equity.value && equity.value < high);
+    assert!(equity.differs_from(&result.equity(2)));
+    assert!(!equity.differs_from(&same.equity(0)));
+    let other_seed = simulate(&scenario, 25_000, 43).unwrap();
+    assert_ne!(result.players[0].wins, other_seed.players[0].wins);
+
+    let adaptive = simulate_adaptive(&scenario, Stop::Precision(0.005), 42).unwrap();
+    assert_eq!(adaptive.games() % (BATCH_CHUNKS * CHUNK_GAMES), 0);
+    assert!(adaptive.equity(0).margin() <= 0.005);
+
+    // without a target there is no adaptive simulation, an elapsed duration stops after a batch
+    assert_eq!(Stop::new(None, None), None);
+    let stop = Stop::new(None, Some(Duration::ZERO)).unwrap();
+    let timed = simulate_adaptive(&scenario, stop, 42).unwrap();
+    assert_eq!(timed.games(), BATCH_CHUNKS * CHUNK_GAMES);
+
+    // combos of the range blocked by known cards are never dealt
+    let rng = &mut StdRng::seed_from_u64(0);
+    let mut hands = Vec::new();
+    for _ in 0..1000 {
+        hands.clear();
+        let table = scenario.deal(rng, &mut scenario.deck(), &mut hands);
+        assert!((hands[1] & (table | hands[0] | hands[2])).is_empty());
+    }
+    let heads_up = Scenario {
+        holdings: scenario.holdings[..2].to_vec(),
+        ..scenario
+    };
+    let equity = simulate(&heads_up, 100_000, 42).unwrap().equity(0);
+    assert!(!equity.differs_from(&enumerate(&heads_up).unwrap().equity(0)));
+}
+
+fn binomial(n: u32, k: u32) -> f64 {
+    (0..k).map(|i| (n - i) as f64 / (i + 1) as f64).product()
+}
+
+/// Deals every choice of the first step from `deck`, then recursively of the following steps
+/// from what is left, calling `visit` with all the cards dealt once no step is left
+fn deal_all(
+    deck: CardSet,
+    steps: &[Step],
+    dealt: &mut Vec<CardSet>,
+    visit: &mut impl FnMut(&[CardSet]),
+) {
+    match steps.split_first() {
+        None => visit(dealt),
+        Some((step, rest)) => {
+            for cards in step.choices(deck) {
+                dealt.push(cards);
+                deal_all(deck & !cards, rest, dealt, visit);
+                dealt.pop();
+            }
+        }
+    }
+}
+
+/// Exact result over every possible table runout and missing cards of each player
+pub fn enumerate(scenario: &Scenario) -> Result<SimulationResult, ScenarioError> {
+    scenario.validate()?;
+    let players = scenario.players();
+    let steps = scenario.steps();
+    // deal the first steps up front, until there are enough branches to split among threads
+    let mut branches = vec![(scenario.deck(), Vec::with_capacity(steps.len()))];
+    let mut level = 0;
+    while level < steps.len() && branches.len() < 1024 {
+        let step = &steps[level];
+        branches = branches
+            .into_iter()
+            .flat_map(|(deck, dealt)| {
+                step.choices(deck).into_iter().map(move |cards| {
+                    let mut dealt = dealt.clone();
+                    dealt.push(cards);
+                    (deck & !cards, dealt)
+                })
+            })
+            .collect();
+        level += 1;
+    }
+    let result = branches
+        .into_par_iter()
+        .fold(
+            || SimulationResult::new(players),
+            |mut result, (deck, mut dealt)| {
+                let mut hands = Vec::with_capacity(players);
+                let mut combs = Vec::with_capacity(players);
+                let mut lows = Vec::with_capacity(players);
+                deal_all(deck, &steps[level..], &mut dealt, &mut |dealt| {
+                    let table = scenario.table | dealt[0];
+                    hands.clear();
+                    hands.extend(
+                        scenario
+                            .holdings
+                            .iter()
+                            .zip(&dealt[1..])
+                            .map(|(holding, &cards)| holding.known_cards() | cards),
+                    );
+                    scenario.evaluate(&hands, table, &mut combs, &mut lows);
+                    result.record_showdown(scenario.game, &combs, &lows);
+                });
+                result
+            },
+        )
+        .reduce(|| SimulationResult::new(players), SimulationResult::merge);
+    Ok(SimulationResult {
+        exact: true,
+        ..result
+    })
+}
+
+#[test]
+fn enumerate_river() {
+    let scenario = holdem("2C3C4P5F6Q", &["AQAC", ""]);
+    let result = enumerate(&scenario).unwrap();
+    assert_eq!(scenario.count_deals(), 990.0);
+    assert_eq!(result.games(), 990);
+    let you = result.players[0];
+    assert_eq!(you.wins + you.ties + you.losses, 990);
+    assert_eq!(you.wins, 0);
+    // only hands holding a 7 beat the straight on the table
+    assert_eq!(you.losses, 4 * 41 + 6);
+    assert_eq!(result.players[1].wins, you.losses);
+
+    let scenario = holdem("10PJPQPKPAP", &["2Q", ""]);
+    let result = enumerate(&scenario).unwrap();
+    assert_eq!(result.games() as f64, scenario.count_deals());
+    assert_eq!(result.players[0].ties, result.games());
+    assert_eq!(result.players[0].equity(), 0.5);
+    assert_eq!(result.equity(0).standard_error, 0.0);
+}
+
+#[test]
+fn enumerate_categories() {
+    let scenario = holdem("2C7P9FKQ", &["AQAC", "KCKP"]);
+    let result = enumerate(&scenario).unwrap();
+    assert_eq!(result.games(), 44);
+    // an ace gives us tris, pairing the table or the last king gives us two pairs
+    assert_eq!(result.categories[CombinationType::Tris.index()], 2);
+    assert_eq!(result.categories[CombinationType::TwoPairs.index()], 10);
+    assert_eq!(result.categories[CombinationType::Pair.index()], 32);
+    // the kings always win or get better
+    assert_eq!(result.winning_categories[CombinationType::Poker.index()], 1);
+    assert_eq!(
+        result.winning_categories[CombinationType::FullHouse.index()],
+        9
+    );
+    assert_eq!(result.winning_categories[CombinationType::Tris.index()], 34);
+    assert_eq!(
+        result.hand_category(CombinationType::Pair).value,
+        32.0 / 44.0
+    );
+    assert_eq!(result.winning_category(CombinationType::Flush).value, 0.0);
+}
+
+#[test]
+fn enumerate_omaha() {
+    let scenario = Scenario {
+        game: Game::Omaha4,
+        ..holdem("2P5P8PJPKP", &["AQACAP3F", "QCQQ3P4P"])
+    };
+    let result = enumerate(&scenario).unwrap();
+    assert_eq!(result.games(), 1);
+    // a single spade in hand does not make a flush in omaha
+    assert_eq!(result.players[1].wins, 1);
+    assert_eq!(result.winning_categories[CombinationType::Flush.index()], 1);
+
+    let scenario = Scenario {
+        table: CardSet::from_str("2P5P8PJP").unwrap(),
+        ..scenario
+    };
+    let result = enumerate(&scenario).unwrap();
+    assert_eq!(result.games(), 52 - 4 - 8);
+    // with a single spade we never make a flush, while the opponent already has one
+    assert_eq!(result.players[0].wins, 0);
+    assert_eq!(result.players[1].wins, result.games());
+}
+
+#[test]
+fn enumerate_stud() {
+    let scenario = Scenario {
+        game: Game::Stud,
+        ..holdem("", &["AQACAPKQKC2F3F", "QPJP10P9P2Q3Q"])
+    };
+    let result = enumerate(&scenario).unwrap();
+    assert_eq!(result.games(), 52 - 7 - 6);
+    // the opponent needs the king or the eight of spades for a straight flush
+    assert_eq!(result.players[1].wins, 2);
+    assert_eq!(result.players[0].wins, result.games() - 2);
+    assert_eq!(
+        result.categories[CombinationType::FullHouse.index()],
+        result.games()
+    );
+}
+
+#[test]
+fn enumerate_hilo() {
+    let scenario = Scenario {
+        game: Game::Omaha4HiLo,
+        ..holdem("4C5P8QKQJF", &["AQ2QKCKP", "AF2FQCQP"])
+    };
+    let result = enumerate(&scenario).unwrap();
+    assert_eq!(result.games(), 1);
+    assert_eq!(result.lows, 1);
+    // we win the high and split the low, getting three quarters of the pot
+    assert_eq!(result.equity(0).value, 0.75);
+    assert_eq!(result.equity(1).value, 0.25);
+    assert_eq!(result.high_equity(0).value, 1.0);
+    assert_eq!(result.low_equity(0).value, 0.5);
+    assert_eq!(result.low_equity(1).value, 0.5);
+    assert_eq!(result.scoop(0).value, 0.0);
+
+    // without a low on the table the high takes the whole pot
+    let scenario = Scenario {
+        table: CardSet::from_str("4C9P7QKQJF").unwrap(),
+        ..scenario
+    };
+    let result = enumerate(&scenario).unwrap();
+    assert_eq!(result.lows, 0);
+    assert_eq!(result.scoop(0).value, 1.0);
+    assert_eq!(result.equity(0).value, 1.0);
+}
+
+#[test]
+fn enumerate_known_opponents() {
+    let scenario = holdem("2C7P9FKQ", &["AQAC", "KCKP"]);
+    let result = enumerate(&scenario).unwrap();
+    assert_eq!(result.games(), 44);
+    // only the two aces left save us
+    assert_eq!(result.players[0].wins, 2);
+    assert_eq!(result.players[1].wins, 42);
+    let equity: f64 = result.players.iter().map(|player| player.equity()).sum();
+    assert!((equity - 1.0).abs() < 1e-9);
+
+    let scenario = Scenario {
+        dead: CardSet::from_str("APAF").unwrap(),
+        ..scenario
+    };
+    let result = enumerate(&scenario).unwrap();
+    assert_eq!(result.games(), 42);
+    assert_eq!(result.players[0].wins, 0);
+}
+
+/// How the result is computed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Engine {
+    /// exact enumeration when it takes no more deals than the requested games, else Monte Carlo
+    Auto,
+    Exact,
+    MonteCarlo,
+}
+
+impl FromStr for Engine {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "auto" => Ok(Engine::Auto),
+            "exact" => Ok(Engine::Exact),
+            "montecarlo" | "mc" => Ok(Engine::MonteCarlo),
+            _ => Err(format!(
+                "unknown engine '{}', expected auto, exact or montecarlo",
+                s
+            )),
+        }
+    }
+}
+
+impl Engine {
+    /// Whether `scenario` is enumerated rather than simulated with `games` games
+    pub fn is_exact(self, scenario: &Scenario, games: u64) -> bool {
+        match self {
+            Engine::Exact => true,
+            Engine::MonteCarlo => false,
+            Engine::Auto => scenario.count_deals() <= games as f64,
+        }
+    }
+    /// Result of `scenario`, enumerated or simulated with `games` games
+    pub fn run(
+        self,
+        scenario: &Scenario,
+        games: u64,
+        seed: u64,
+    ) -> Result<SimulationResult, ScenarioError> {
+        if self.is_exact(scenario, games) {
+            enumerate(scenario)
+        } else {
+            simulate(scenario, games, seed)
+        }
+    }
+}
+
+/// Result of the rest of the game after each way of dealing `cards` more cards on the table,
+/// splitting `games` among the runouts, with at least a chunk of games each.
+/// Each runout comes with its weight, the number of ways of dealing the ranges along with it,
+/// since cards taken by the ranges make some runouts less likely than others
+pub fn runout_results(
+    scenario: &Scenario,
+    cards: u32,
+    engine: Engine,
+    games: u64,
+    seed: u64,
+) -> Result<Vec<(CardSet, f64, SimulationResult)>, ScenarioError> {
+    scenario.validate()?;
+    let deck = scenario.deck();
+    let games = (games / binomial(deck.count_cards(), cards) as u64).max(CHUNK_GAMES);
+    deck.subsets(cards)
+        .collect::<Vec<CardSet>>()
+        .into_par_iter()
+        .filter_map(|runout| {
+            let next = scenario.with_table_cards(runout)?;
+            let weight = scenario.runout_weight(runout);
+            Some(
+                engine
+                    .run(&next, games, seed)
+                    .map(|result| (runout, weight, result)),
+            )
+        })
+        .collect()
+}
+
+/// Average of the values of `(weight, value)` pairs, weighted by their weights
+pub fn weighted_average(values: impl Iterator<Item = (f64, f64)>) -> f64 {
+    let (sum, total) = values.fold((0.0, 0.0), |(sum, total), (weight, value)| {
+        (sum + weight * value, total + weight)
+    });
+    sum / total
+}
+
+#[test]
+fn runouts_average_to_equity() {
+    let scenario = holdem("2C7P9F", &["AQAC", "KCKP"]);
+    let equity = enumerate(&scenario).unwrap().equity(0).value;
+    for cards in 1..=2 {
+        let runouts = runout_results(&scenario, cards, Engine::Exact, 0, 0).unwrap();
+        assert_eq!(runouts.len() as f64, binomial(45, cards));
+        assert!(runouts.iter().all(|&(_, weight, _)| weight == 1.0));
+        let average = weighted_average(
+            runouts
+                .iter()
+                .map(|(_, weight, result)| (*weight, result.equity(0).value)),
+        );
+        assert!((average - equity).abs() < 1e-12);
+    }
+
+    // kings on the table leave fewer combos of kings to the range
+    let scenario = Scenario {
+        holdings: vec![
+            Holding::Cards(CardSet::from_str("AQAC").unwrap()),
+            Holding::Range(Range::from_str("KK,AKs,72o").unwrap()),
+        ],
+        ..scenario
+    };
+    let equity = enumerate(&scenario).unwrap().equity(0).value;
+    for cards in 1..=2 {
+        let runouts = runout_results(&scenario, cards, Engine::Exact, 0, 0).unwrap();
+        let average = weighted_average(
+            runouts
+                .iter()
+                .map(|(_, weight, result)| (*weight, result.equity(0).value)),
+        );
+        assert!((average - equity).abs() < 1e-12);
+    }
+}
+
+/// Our hand and equity once a card is dealt on the table
+#[derive(Clone, Copy)]
+pub struct Runout {
+    pub card: CardSet,
+    /// our hand with the card
+    pub combination: Combination,
+    pub equity: Estimate,
+}
+
+/// Our hand and equity after each card that can be dealt next on the table,
+/// from the best card for us to the worst
+pub fn next_card_runouts(
+    scenario: &Scenario,
+    engine: Engine,
+    games: u64,
+    seed: u64,
+) -> Result<Vec<Runout>, ScenarioError> {
+    let results = runout_results(scenario, 1, engine, games, seed)?;
+    let hand = scenario.holdings[0].known_cards();
+    let mut runouts: Vec<Runout> = results
+        .into_iter()
+        .map(|(card, _, result)| Runout {
+            card,
+            combination: scenario.game.evaluate_with(
+                scenario.evaluator,
+                hand,
+                scenario.table | card,
+            ),
+            equity: result.equity(0),
+        })
+        .collect();
+    runouts.sort_by(|a, b| b.equity.value.partial_cmp(&a.equity.value).unwrap());
+    Ok(runouts)
+}
+
+/// Card that improves our hand to the lead
+#[derive(Clone, Copy, Debug)]
+pub struct Out {
+    pub card: CardSet,
+    /// category of our hand with the card
+    pub category: CombinationType,
+}
+
+/// Cards that give us a better category, thanks to our hand, and a higher equity
+/// than every opponent, sorted by category
+pub fn outs(
+    scenario: &Scenario,
+    engine: Engine,
+    games: u64,
+    seed: u64,
+) -> Result<Vec<Out>, ScenarioError> {
+    let results = runout_results(scenario, 1, engine, games, seed)?;
+    let hand = scenario.holdings[0].known_cards();
+    let current = scenario
+        .game
+        .evaluate_with(scenario.evaluator, hand, scenario.table);
+    let mut outs: Vec<Out> = results
+        .into_iter()
+        .filter_map(|(card, _, result)| {
+            let category = scenario
+                .game
+                .evaluate_with(scenario.evaluator, hand, scenario.table | card)
+                .category();
+            // a card pairing the table improves everyone, not only us
+            let improves = category.index() > current.category().index()
+                && category.index()
+                    > scenario
+                        .game
+                        .evaluate(CardSet::none(), scenario.table | card)
+                        .category()
+                        .index();
+            let equity = result.equity(0).value;
+            let leading = (1..scenario.players()).all(|i| equity > result.equity(i).value);
+            if improves && leading {
+                Some(Out { card, category })
+            } else {
+                None
+            }
+        })
+        .collect();
+    outs.sort_by_key(|out| out.category.index());
+    Ok(outs)
+}
+
+/// Probability of one of the `outs` cards coming on the rest of the table,
+/// each runout being as likely as the ways of dealing the ranges along with it
+pub fn hit_probability(scenario: &Scenario, outs: CardSet) -> f64 {
+    let deck = scenario.deck();
+    let dealt = scenario.game.table_cards() - scenario.table.count_cards();
+    if scenario
+        .holdings
+        .iter()
+        .all(|holding| matches!(holding, Holding::Cards(_)))
+    {
+        let (unseen, outs) = (deck.count_cards(), (outs & deck).count_cards());
+        return 1.0 - binomial(unseen - outs, dealt) / binomial(unseen, dealt);
+    }
+    // ranges take some cards more often than others, so every runout is weighted
+    let (hits, total) = deck
+        .subsets(dealt)
+        .collect::<Vec<CardSet>>()
+        .into_par_iter()
+        .map(|runout| {
+            let weight = scenario.runout_weight(runout);
+            let hit = if (runout & outs).is_empty() {
+                0.0
+            } else {
+                weight
+            };
+            (hit, weight)
+        })
+        .reduce(|| (0.0, 0.0), |(a, b), (c, d)| (a + c, b + d));
+    hits / total
+}
+
+#[test]
+fn flush_outs() {
+    let scenario = holdem("2Q7Q9P", &["AQKQ", "9C9F"]);
+    let outs = outs(&scenario, Engine::Auto, 1_000_000, 0).unwrap();
+    // every diamond but the nine, which gives the opponent poker
+    assert_eq!(outs.len(), 8);
+    assert!(outs
+        .iter()
+        .all(|out| out.category == CombinationType::Flush));
+    assert!(outs
+        .iter()
+        .all(|out| out.card != CardSet::from_str("9Q").unwrap()));
+    assert_eq!(scenario.deck().count_cards(), 45);
+
+    let runouts = next_card_runouts(&scenario, Engine::Auto, 1_000_000, 0).unwrap();
+    assert_eq!(runouts.len(), 45);
+    assert!(runouts
+        .windows(2)
+        .all(|pair| pair[0].equity.value >= pair[1].equity.value));
+    assert!(runouts[..8]
+        .iter()
+        .all(|runout| runout.combination.category() == CombinationType::Flush));
+    // the last nine gives the opponent poker
+    let nine = CardSet::from_str("9Q").unwrap();
+    let nine = runouts.iter().find(|runout| runout.card == nine).unwrap();
+    assert_eq!(nine.equity.value, 0.0);
+    assert_eq!(runouts[44].equity.value, 0.0);
+    let diamonds = outs
+        .iter()
+        .fold(CardSet::none(), |cards, out| cards | out.card);
+    assert!((hit_probability(&scenario, diamonds) - (1.0 - 666.0 / 990.0)).abs() < 1e-12);
+    let turn = Scenario {
+        table: CardSet::from_str("2Q7Q9P5C").unwrap(),
+        ..scenario.clone()
+    };
+    assert!((hit_probability(&turn, diamonds) - 8.0 / 44.0).abs() < 1e-12);
+
+    // a range of threes holds the three of diamonds more often than any other diamond:
+    // each of the 42 other cards leaves 6 combos of threes, each three leaves 3
+    let range = Scenario {
+        holdings: vec![
+            Holding::Cards(CardSet::from_str("AQKQ").unwrap()),
+            Holding::Range(Range::from_str("33").unwrap()),
+        ],
+        ..turn
+    };
+    let diamonds = CardSet::from_str("3Q4Q5Q6Q8Q9Q10QJQQQ").unwrap();
+    let expected = (8.0 * 6.0 + 3.0) / (42.0 * 6.0 + 4.0 * 3.0);
+    assert!((hit_probability(&range, diamonds) - expected).abs() < 1e-12);
+}
+
+#[test]
+fn short_deck_outs() {
+    let scenario = Scenario {
+        game: Game::ShortDeck,
+        ..holdem("AF6C7Q8P", &["10P10Q", "JPJQ"])
+    };
+    // a nine makes A-6-7-8-9 on the table, only the tens improve us alone
+    let outs = outs(&scenario, Engine::Auto, 1_000_000, 0).unwrap();
+    assert_eq!(outs.len(), 2);
+    assert!(outs.iter().all(|out| out.category == CombinationType::Tris));
+}
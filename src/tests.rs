@@ -0,0 +1,897 @@
+use super::game::Game;
+use super::range::{Range, RangeParseError};
+use super::{
+    cardset::CardSet, combination::Combination, combination::CombinationRanks,
+    combination::CombinationType,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use std::str::FromStr;
+
+fn check_parse(input: &str, card_index: u32) {
+    assert_eq!(CardSet::from_str(input), Ok(CardSet::one(card_index)))
+}
+
+#[test]
+fn none_all() {
+    assert_eq!(!CardSet::none(), CardSet::all());
+}
+
+#[test]
+fn parse() {
+    check_parse("2♦", 0);
+    check_parse("3♦", 1);
+    check_parse("4♦", 2);
+    check_parse("5♦", 3);
+    check_parse("6♦", 4);
+    check_parse("7♦", 5);
+    check_parse("8♦", 6);
+    check_parse("9♦", 7);
+    check_parse("10♦", 8);
+    check_parse("J♦", 9);
+    check_parse("Q♦", 10);
+    check_parse("K♦", 11);
+    check_parse("A♦", 12);
+
+    check_parse("2♥", 13);
+    check_parse("3♥", 14);
+    check_parse("4♥", 15);
+    check_parse("5♥", 16);
+    check_parse("6♥", 17);
+    check_parse("7♥", 18);
+    check_parse("8♥", 19);
+    check_parse("9♥", 20);
+    check_parse("10♥", 21);
+    check_parse("J♥", 22);
+    check_parse("Q♥", 23);
+    check_parse("K♥", 24);
+    check_parse("A♥", 25);
+
+    check_parse("2♠", 26);
+    check_parse("3♠", 27);
+    check_parse("4♠", 28);
+    check_parse("5♠", 29);
+    check_parse("6♠", 30);
+    check_parse("7♠", 31);
+    check_parse("8♠", 32);
+    check_parse("9♠", 33);
+    check_parse("10♠", 34);
+    check_parse("J♠", 35);
+    check_parse("Q♠", 36);
+    check_parse("K♠", 37);
+    check_parse("A♠", 38);
+
+    check_parse("2♣", 39);
+    check_parse("3♣", 40);
+    check_parse("4♣", 41);
+    check_parse("5♣", 42);
+    check_parse("6♣", 43);
+    check_parse("7♣", 44);
+    check_parse("8♣", 45);
+    check_parse("9♣", 46);
+    check_parse("10♣", 47);
+    check_parse("J♣", 48);
+    check_parse("Q♣", 49);
+    check_parse("K♣", 50);
+    check_parse("A♣", 51);
+}
+
+#[test]
+fn parse_multiple() {
+    assert_eq!(
+        CardSet::from_str("8♠2♣Q♣"),
+        Ok(CardSet::from_str("8♠").unwrap()
+            | CardSet::from_str("2♣").unwrap()
+            | CardSet::from_str("Q♣").unwrap())
+    );
+    assert_eq!(CardSet::from_str("8♠2♣Q♣").unwrap().count_cards(), 3);
+}
+
+fn comb(input: &[&str]) -> Combination {
+    input
+        .iter()
+        .map(|s| CardSet::from_str(s).unwrap())
+        .fold(CardSet::none(), |a, b| a | b)
+        .comb()
+}
+
+/*
+StraightFlush
+Poker
+Straight */
+#[test]
+fn straight_flush() {
+    assert_eq!(
+        comb(&["2♥", "3♥", "4♥", "5♥", "6♥"]).category(),
+        CombinationType::StraightFlush
+    );
+    assert_eq!(
+        comb(&["10♠", "J♠", "Q♠", "K♠", "A♠"]).category(),
+        CombinationType::RoyalFlush
+    );
+    assert!(comb(&["10♠", "J♠", "Q♠", "K♠", "A♠"]) > comb(&["2♠", "3♥", "4♠", "5♥", "6♠"]));
+    assert!(comb(&["10♠", "J♠", "Q♠", "K♠", "A♠"]) > comb(&["9♠", "10♠", "J♠", "Q♠", "K♠"]));
+    assert!(comb(&["2♥", "3♥", "4♥", "5♥", "6♥"]) > comb(&["10♠", "J♥", "Q♠", "K♠", "A♠"]));
+    assert!(comb(&["2♥", "3♥", "4♥", "5♥", "6♥"]) > comb(&["A♠", "A♥", "A♦", "A♣", "K♠"]));
+}
+/*
+StraightFlush
+Poker
+FullHouse*/
+#[test]
+fn poker() {
+    assert_eq!(
+        comb(&["10♠", "10♥", "10♦", "10♣", "3♠"]).category(),
+        CombinationType::Poker
+    );
+    assert!(comb(&["10♠", "10♥", "10♦", "10♣", "4♠"]) > comb(&["10♠", "10♥", "10♦", "10♣", "3♠"]));
+    assert!(comb(&["10♠", "10♥", "10♦", "10♣", "2♠"]) > comb(&["9♠", "9♥", "9♦", "9♣", "A♠"]));
+    assert!(comb(&["2♠", "2♥", "2♦", "2♣", "3♠"]) > comb(&["A♠", "A♥", "A♦", "Q♣", "Q♠"]));
+}
+/*
+Poker
+FullHouse
+Flush*/
+#[test]
+fn full_house() {
+    assert_eq!(
+        comb(&["10♠", "10♥", "10♦", "2♣", "2♠"]).category(),
+        CombinationType::FullHouse
+    );
+    assert_eq!(
+        comb(&["2♠", "2♥", "2♦", "10♣", "10♠"]).category(),
+        CombinationType::FullHouse
+    );
+    assert!(comb(&["A♠", "A♥", "A♦", "Q♣", "Q♠"]) > comb(&["A♠", "A♥", "A♦", "J♣", "J♠"]));
+    assert!(comb(&["10♠", "10♥", "10♦", "2♣", "2♠"]) > comb(&["9♠", "9♥", "9♦", "A♣", "A♠"]));
+    assert!(comb(&["2♠", "2♥", "2♦", "3♣", "3♠"]) > comb(&["Q♥", "K♥", "A♥", "K♦", "A♦"]));
+    assert!(comb(&["2♠", "2♥", "2♦", "3♣", "3♠"]) > comb(&["A♥", "K♥", "A♦", "K♦", "Q♦"]));
+}
+/*
+FullHouse
+Flush
+Straight
+*/
+#[test]
+fn flush() {
+    assert_eq!(
+        comb(&["A♥", "2♥", "3♥", "4♥", "6♥"]).category(),
+        CombinationType::Flush
+    );
+    assert_eq!(
+        comb(&["2♣", "3♣", "4♣", "5♣", "7♣"]).category(),
+        CombinationType::Flush
+    );
+    assert_eq!(
+        comb(&["9♠", "J♠", "Q♠", "K♠", "A♠"]).category(),
+        CombinationType::Flush
+    );
+    assert_eq!(
+        comb(&["10♠", "J♥", "Q♠", "K♠", "A♠"]).category(),
+        CombinationType::Straight
+    );
+    assert!(comb(&["3♥", "4♥", "5♥", "7♥", "9♥"]) > comb(&["2♥", "3♥", "4♥", "5♥", "7♥"]));
+    assert!(comb(&["2♥", "4♥", "6♥", "7♥", "9♥"]) > comb(&["2♥", "3♥", "6♥", "7♥", "9♥"]));
+    assert!(comb(&["2♥", "3♥", "4♥", "6♥", "7♥"]) > comb(&["10♠", "J♥", "Q♠", "K♠", "A♠"]));
+    assert!(comb(&["2♥", "3♥", "4♥", "5♥", "7♥"]) == comb(&["2♣", "3♣", "4♣", "5♣", "7♣"]));
+    assert!(comb(&["2♥", "3♥", "4♥", "6♥", "7♥"]) < comb(&["2♥", "2♣", "2♦", "3♦", "3♣"]));
+}
+/*
+Flush
+Straight
+Tris */
+#[test]
+fn straight() {
+    assert_eq!(
+        comb(&["3♠", "4♥", "5♠", "6♠", "7♠"]).category(),
+        CombinationType::Straight
+    );
+    assert_eq!(
+        comb(&["A♠", "2♥", "3♥", "4♠", "5♠"]).category(),
+        CombinationType::Straight
+    );
+    assert_eq!(
+        comb(&["2♠", "4♥", "5♠", "6♠", "7♠"]).category(),
+        CombinationType::HighCard
+    );
+    assert_eq!(
+        comb(&["10♠", "J♥", "Q♠", "K♠", "A♠"]).category(),
+        CombinationType::Straight
+    );
+    assert!(comb(&["10♠", "J♥", "Q♠", "K♠", "A♠"]) > comb(&["9♠", "10♠", "J♠", "Q♥", "K♠"]));
+    assert!(comb(&["2♠", "3♥", "4♠", "5♠", "6♠"]) > comb(&["A♠", "2♠", "3♠", "4♥", "5♠"]));
+    assert!(comb(&["3♥", "4♠", "5♠", "6♠", "7♠"]) > comb(&["2♠", "3♥", "4♠", "5♠", "6♠"]));
+    assert!(comb(&["2♠", "3♥", "4♠", "5♠", "6♠"]) > comb(&["A♠", "A♥", "A♣", "Q♥", "K♠"]));
+}
+/*
+Straight
+Tris
+TwoPairs */
+#[test]
+fn tris() {
+    assert_eq!(
+        comb(&["5♠", "5♥", "5♣", "Q♥", "K♠"]).category(),
+        CombinationType::Tris
+    );
+    assert!(comb(&["5♠", "5♥", "5♣", "Q♥", "K♠"]) > comb(&["5♠", "5♥", "5♣", "Q♥", "J♠"]));
+    assert!(comb(&["5♠", "5♥", "5♣", "Q♥", "K♠"]) > comb(&["5♠", "5♥", "5♣", "10♥", "J♠"]));
+    assert!(comb(&["7♠", "7♥", "7♣", "2♥", "2♠"]) > comb(&["6♠", "6♥", "6♣", "A♥", "A♠"]));
+    assert!(comb(&["2♠", "2♥", "2♣", "3♥", "4♠"]) > comb(&["A♠", "A♥", "K♣", "K♥", "Q♠"]));
+}
+/*
+Tris
+TwoPairs
+Pair */
+#[test]
+fn two_pairs() {
+    assert_eq!(
+        comb(&["10♠", "10♥", "Q♠", "Q♦", "A♠"]).category(),
+        CombinationType::TwoPairs
+    );
+    assert!(comb(&["10♠", "10♥", "Q♠", "Q♦", "A♠"]) > comb(&["10♠", "10♥", "Q♠", "Q♦", "J♠"]));
+    assert!(comb(&["10♠", "10♥", "K♠", "K♦", "2♠"]) > comb(&["10♠", "10♥", "Q♠", "Q♦", "A♠"]));
+    assert!(comb(&["10♠", "10♥", "Q♠", "Q♦", "A♠"]) > comb(&["10♠", "10♥", "Q♠", "Q♦", "2♠"]));
+    assert!(comb(&["2♠", "2♥", "Q♠", "Q♦", "3♠"]) > comb(&["10♠", "10♥", "J♠", "J♦", "A♠"]));
+    assert!(comb(&["2♠", "2♥", "3♠", "3♦", "4♠"]) > comb(&["A♠", "A♥", "Q♠", "K♦", "J♠"]));
+}
+/*
+TwoPairs
+Pair
+HighCard */
+#[test]
+fn pair() {
+    assert_eq!(
+        comb(&["2♠", "2♥", "3♠", "4♦", "5♠"]).category(),
+        CombinationType::Pair
+    );
+    assert!(comb(&["10♠", "10♥", "2♠", "3♦", "5♠"]) > comb(&["10♠", "10♥", "2♠", "3♦", "4♠"]));
+    assert!(comb(&["10♠", "10♥", "2♠", "3♦", "4♠"]) > comb(&["9♠", "9♥", "A♠", "K♦", "Q♠"]));
+    assert!(comb(&["10♠", "10♥", "Q♠", "Q♦", "A♠"]) > comb(&["10♠", "10♥", "Q♠", "Q♦", "2♠"]));
+    assert!(comb(&["2♠", "2♥", "3♠", "4♦", "5♠"]) > comb(&["A♠", "9♥", "Q♠", "K♦", "J♠"]));
+}
+/*
+TwoPairs
+Pair
+HighCard */
+#[test]
+fn high_card() {
+    assert_eq!(
+        comb(&["A♠", "K♥", "2♠", "3♠", "4♠"]).category(),
+        CombinationType::HighCard
+    );
+    assert!(comb(&["A♠", "J♥", "Q♠", "K♠", "9♠"]) > comb(&["A♠", "J♥", "Q♠", "K♠", "8♠"]));
+    assert!(comb(&["A♠", "10♥", "7♠", "6♠", "5♠"]) > comb(&["A♠", "9♥", "7♠", "6♠", "5♠"]));
+    assert!(comb(&["A♠", "2♥", "3♠", "4♠", "5♠"]) > comb(&["K♠", "Q♥", "J♠", "10♠", "8♠"]));
+    assert!(comb(&["7♥", "A♦", "2♠", "5♠", "7♣"]) > comb(&["5♥", "A♦", "2♠", "5♠", "7♣"]));
+}
+
+#[test]
+fn draw() {
+    let rng = &mut StdRng::seed_from_u64(0);
+    assert_eq!(CardSet::all().draw(52, rng), CardSet::all());
+    assert_eq!(CardSet::all().draw(0, rng), CardSet::none());
+    let mut deck = CardSet::all();
+    let h1 = deck.draw(7, rng);
+    assert_eq!(deck.count_cards(), 52 - 7);
+    assert_eq!(h1.count_cards(), 7);
+    let h1 = deck.draw(5, rng);
+    assert_eq!(deck.count_cards(), 52 - 7 - 5);
+    assert_eq!(h1.count_cards(), 5);
+}
+
+#[test]
+fn draw_seeded() {
+    let draws = |seed| {
+        let rng = &mut StdRng::seed_from_u64(seed);
+        let mut deck = CardSet::all();
+        (0..10).map(|_| deck.draw(5, rng)).collect::<Vec<_>>()
+    };
+    assert_eq!(draws(42), draws(42));
+    assert_ne!(draws(42), draws(43));
+    let range = Range::from_str("QQ+,AKs").unwrap();
+    let rng = &mut StdRng::seed_from_u64(7);
+    let combos: Vec<CardSet> = (0..20).map(|_| range.sample(rng)).collect();
+    let rng = &mut StdRng::seed_from_u64(7);
+    assert!(combos.iter().all(|&combo| combo == range.sample(rng)));
+}
+
+#[test]
+fn subsets() {
+    let cards = CardSet::from_str("2♦5♥K♠A♣").unwrap();
+    assert_eq!(cards.subsets(0).collect::<Vec<_>>(), vec![CardSet::none()]);
+    assert_eq!(cards.subsets(4).collect::<Vec<_>>(), vec![cards]);
+    assert_eq!(cards.subsets(5).count(), 0);
+    let pairs: Vec<CardSet> = cards.subsets(2).collect();
+    assert_eq!(pairs.len(), 6);
+    for (i, pair) in pairs.iter().enumerate() {
+        assert_eq!(pair.count_cards(), 2);
+        assert_eq!(*pair & cards, *pair);
+        assert!(pairs[i + 1..].iter().all(|other| other != pair));
+    }
+    assert_eq!(CardSet::all().subsets(2).count(), 1326);
+}
+
+#[test]
+fn range_notation() {
+    let range = |s: &str| Range::from_str(s).unwrap();
+    assert_eq!(range("QQ").len(), 6);
+    assert_eq!(range("AKs").len(), 4);
+    assert_eq!(range("AKo").len(), 12);
+    assert_eq!(range("AK").len(), 16);
+    assert_eq!(range("QQ+").len(), 18);
+    assert_eq!(range("22-55").len(), 24);
+    assert_eq!(range("AJo+").len(), 36);
+    assert_eq!(range("A2s-A5s").len(), 16);
+    assert_eq!(range("A5s-A2s"), range("A2s,A3s,A4s,A5s"));
+    assert_eq!(range("T9s"), range("109s"));
+    assert_eq!(range("QQ+,AKs,AJo+,76s").len(), 18 + 4 + 36 + 4);
+    assert_eq!(range("AK,AKs").len(), 16);
+    assert_eq!(
+        range("AQKQ").combos(),
+        &[CardSet::from_str("A♦K♦").unwrap()]
+    );
+    assert_eq!(
+        Range::from_str("AXs"),
+        Err(RangeParseError::InvalidHand("AXs".to_string()))
+    );
+    assert_eq!(
+        Range::from_str("QQs"),
+        Err(RangeParseError::InvalidHand("QQs".to_string()))
+    );
+    assert_eq!(Range::from_str("AK+"), Ok(range("AK")));
+    assert!(Range::from_str("22-AKs").is_err());
+    let known = CardSet::from_str("Q♦Q♥").unwrap();
+    assert_eq!(range("QQ").without(known).len(), 1);
+    assert_eq!(range("AK").without(known).len(), 16);
+}
+
+#[test]
+fn range_percentage() {
+    assert_eq!(Range::from_str("100%").unwrap().len(), 1326);
+    assert_eq!(
+        Range::from_str("top 0.1%").unwrap(),
+        Range::from_str("AA").unwrap()
+    );
+    let top_15 = Range::from_str("top 15%").unwrap();
+    assert!(top_15.len() >= 199 && top_15.len() < 199 + 12);
+    assert!(Range::from_str("top 0%").is_err());
+    assert!(Range::from_str("top 101%").is_err());
+}
+
+fn cards(input: &[&str]) -> CardSet {
+    input
+        .iter()
+        .map(|s| CardSet::from_str(s).unwrap())
+        .fold(CardSet::none(), |a, b| a | b)
+}
+
+#[test]
+fn omaha() {
+    let table = cards(&["2♠", "5♠", "8♠", "J♠", "K♠"]);
+    let hand = cards(&["A♥", "A♦", "3♣", "4♣"]);
+    // holdem plays the flush on the table, omaha must use two cards of the hand
+    assert_eq!(
+        Game::Holdem
+            .evaluate(cards(&["A♥", "A♦"]), table)
+            .category(),
+        CombinationType::Flush
+    );
+    assert!(Game::Omaha4.evaluate(hand, table) == comb(&["A♥", "A♦", "K♠", "J♠", "8♠"]));
+    let quads = cards(&["A♠", "A♥", "A♦", "A♣"]);
+    let table = cards(&["2♥", "3♦", "7♠"]);
+    assert_eq!(
+        Game::Holdem.evaluate(quads, table).category(),
+        CombinationType::Poker
+    );
+    assert_eq!(
+        Game::Omaha4.evaluate(quads, table).category(),
+        CombinationType::Pair
+    );
+    // pairing the table with the fifth card is worse than the pair of aces
+    assert!(
+        Game::Omaha5.evaluate(quads | cards(&["2♣"]), table) == Game::Omaha4.evaluate(quads, table)
+    );
+    // with fewer than three cards on the table all of them are used
+    assert_eq!(
+        Game::Omaha4.evaluate(hand, cards(&["A♠"])).category(),
+        CombinationType::Tris
+    );
+    assert_eq!(Game::Omaha4.hand_cards(), 4);
+    assert_eq!(Game::Omaha5.hand_cards(), 5);
+}
+
+fn short(input: &[&str]) -> Combination {
+    cards(input).short_deck_comb()
+}
+
+fn short_strength(input: &[&str]) -> u32 {
+    Game::ShortDeck.strength(short(input))
+}
+
+#[test]
+fn short_deck() {
+    assert_eq!(Game::ShortDeck.deck().count_cards(), 36);
+    assert!((Game::ShortDeck.deck() & cards(&["2♠", "3♥", "4♦", "5♣"])).is_empty());
+    assert_eq!(
+        Game::ShortDeck.deck() & cards(&["6♠", "A♥"]),
+        cards(&["6♠", "A♥"])
+    );
+}
+
+#[test]
+fn short_deck_straight() {
+    assert_eq!(
+        short(&["A♠", "6♥", "7♥", "8♠", "9♠"]).category(),
+        CombinationType::Straight
+    );
+    assert_eq!(
+        short(&["A♠", "6♠", "7♠", "8♠", "9♠"]).category(),
+        CombinationType::StraightFlush
+    );
+    assert_eq!(
+        short(&["10♠", "J♥", "Q♠", "K♠", "A♠"]).category(),
+        CombinationType::Straight
+    );
+    assert_eq!(
+        short(&["A♠", "6♥", "7♥", "8♠", "10♠"]).category(),
+        CombinationType::HighCard
+    );
+    assert_eq!(
+        comb(&["A♠", "6♥", "7♥", "8♠", "9♠"]).category(),
+        CombinationType::HighCard
+    );
+    assert!(
+        short_strength(&["6♠", "7♥", "8♠", "9♠", "10♠"])
+            > short_strength(&["A♠", "6♥", "7♥", "8♠", "9♠"])
+    );
+    assert!(
+        short_strength(&["A♠", "6♥", "7♥", "8♠", "9♠"])
+            > short_strength(&["A♠", "A♥", "A♣", "Q♥", "K♠"])
+    );
+    assert!(
+        short_strength(&["A♠", "6♠", "7♠", "8♠", "9♠"])
+            > short_strength(&["A♠", "A♥", "A♦", "A♣", "K♠"])
+    );
+}
+
+#[test]
+fn short_deck_flush() {
+    assert_eq!(
+        short(&["A♥", "6♥", "7♥", "8♥", "10♥"]).category(),
+        CombinationType::Flush
+    );
+    assert_eq!(
+        short(&["10♠", "10♥", "10♦", "6♣", "6♠"]).category(),
+        CombinationType::FullHouse
+    );
+    assert!(
+        short_strength(&["6♥", "7♥", "8♥", "10♥", "J♥"])
+            > short_strength(&["A♠", "A♥", "A♦", "K♣", "K♠"])
+    );
+    assert!(
+        short_strength(&["A♠", "A♥", "A♦", "A♣", "6♠"])
+            > short_strength(&["A♥", "K♥", "Q♥", "J♥", "9♥"])
+    );
+    assert!(
+        short_strength(&["A♠", "A♥", "A♦", "K♣", "K♠"])
+            > short_strength(&["K♠", "K♥", "K♦", "A♣", "A♠"])
+    );
+    assert!(
+        short_strength(&["A♥", "K♥", "Q♥", "J♥", "9♥"])
+            > short_strength(&["A♠", "K♥", "Q♥", "J♥", "9♥"])
+    );
+    // seven cards cannot make both, so a 7-card flush beats a 7-card full house
+    assert_eq!(
+        short(&["6♥", "6♠", "6♦", "7♥", "7♠", "9♥", "J♥"]).category(),
+        CombinationType::FullHouse
+    );
+    assert!(
+        short_strength(&["6♥", "7♥", "9♥", "J♥", "Q♥", "A♠", "A♦"])
+            > short_strength(&["A♠", "A♥", "A♦", "K♣", "K♠", "6♥", "7♦"])
+    );
+    // library only edge case: eight cards making both play the flush
+    assert_eq!(
+        short(&["6♥", "6♠", "6♦", "7♥", "7♠", "9♥", "J♥", "Q♥"]).category(),
+        CombinationType::Flush
+    );
+    assert_eq!(
+        comb(&["6♥", "6♠", "6♦", "7♥", "7♠", "9♥", "J♥", "Q♥"]).category(),
+        CombinationType::FullHouse
+    );
+}
+
+#[test]
+fn low() {
+    assert_eq!(
+        cards(&["A♠", "2♥", "3♦", "4♣", "5♠"]).low().unwrap().name(),
+        "5-4-3-2-A"
+    );
+    assert_eq!(
+        cards(&["8♠", "7♥", "6♦", "5♣", "4♠"]).low().unwrap().name(),
+        "8-7-6-5-4"
+    );
+    assert_eq!(cards(&["9♠", "2♥", "3♦", "4♣", "5♠"]).low(), None);
+    assert_eq!(cards(&["A♠", "A♥", "3♦", "4♣", "5♠"]).low(), None);
+    // straights and flushes do not count, pairs are skipped
+    assert_eq!(
+        cards(&["A♠", "2♠", "3♠", "4♠", "5♠", "A♥", "2♦"])
+            .low()
+            .unwrap()
+            .name(),
+        "5-4-3-2-A"
+    );
+    assert_eq!(
+        cards(&["A♠", "2♥", "3♦", "4♣", "6♠", "7♥", "8♦"])
+            .low()
+            .unwrap()
+            .name(),
+        "6-4-3-2-A"
+    );
+    assert!(
+        cards(&["A♠", "2♥", "3♦", "4♣", "5♠"]).low() > cards(&["A♠", "2♥", "3♦", "4♣", "6♠"]).low()
+    );
+    assert!(
+        cards(&["7♠", "5♥", "4♦", "3♣", "2♠"]).low() > cards(&["8♠", "4♥", "3♦", "2♣", "A♠"]).low()
+    );
+    assert!(
+        cards(&["8♠", "6♥", "4♦", "3♣", "2♠"]).low() > cards(&["8♠", "6♥", "5♦", "2♣", "A♠"]).low()
+    );
+    assert!(
+        cards(&["8♠", "6♥", "4♦", "3♣", "2♠"]).low()
+            == cards(&["8♥", "6♠", "4♣", "3♦", "2♥"]).low()
+    );
+}
+
+#[test]
+fn omaha_low() {
+    let table = cards(&["3♠", "4♥", "5♦", "K♣", "Q♠"]);
+    assert_eq!(
+        Game::Omaha4HiLo
+            .low(cards(&["A♠", "2♥", "K♦", "K♥"]), table)
+            .unwrap()
+            .name(),
+        "5-4-3-2-A"
+    );
+    // three cards up to eight are needed on the table
+    let table = cards(&["5♠", "K♥", "Q♦", "J♣", "10♠"]);
+    assert_eq!(
+        Game::Omaha4HiLo.low(cards(&["A♠", "2♥", "3♦", "4♥"]), table),
+        None
+    );
+    assert_eq!(
+        Game::StudHiLo
+            .low(
+                cards(&["A♠", "2♥", "3♦", "4♥", "K♠", "Q♥", "8♦"]),
+                CardSet::none()
+            )
+            .unwrap()
+            .name(),
+        "8-4-3-2-A"
+    );
+    assert_eq!(
+        Game::Omaha4.low(cards(&["A♠", "2♥", "K♦", "K♥"]), cards(&["3♠", "4♥", "5♦"])),
+        None
+    );
+}
+
+#[test]
+fn best_hand() {
+    let best = cards(&["K♠", "K♥", "7♦", "7♣", "A♥", "2♠", "3♠"]).best_hand();
+    assert_eq!(best.cards, cards(&["K♠", "K♥", "7♦", "7♣"]));
+    assert_eq!(best.kickers, cards(&["A♥"]));
+    let best = cards(&["A♠", "2♥", "3♦", "4♣", "5♠", "9♥", "K♦"]).best_hand();
+    assert_eq!(best.cards, cards(&["A♠", "2♥", "3♦", "4♣", "5♠"]));
+    assert!(best.kickers.is_empty());
+    // the flush takes the five highest cards of its suit
+    let best = cards(&["2♠", "5♠", "8♠", "J♠", "K♠", "A♠", "A♥"]).best_hand();
+    assert_eq!(best.cards, cards(&["5♠", "8♠", "J♠", "K♠", "A♠"]));
+    let best = cards(&["Q♠", "Q♥", "Q♦", "Q♣", "9♠", "8♥", "8♦"]).best_hand();
+    assert_eq!(best.cards, cards(&["Q♠", "Q♥", "Q♦", "Q♣"]));
+    assert_eq!(best.kickers, cards(&["9♠"]));
+    let best = cards(&["A♠", "6♥", "7♦", "8♣", "9♠", "K♥"]).short_deck_best_hand();
+    assert_eq!(best.cards, cards(&["A♠", "6♥", "7♦", "8♣", "9♠"]));
+    assert_eq!(cards(&["A♠", "K♥"]).best_hand().all(), cards(&["A♠", "K♥"]));
+
+    let table = cards(&["2♠", "5♠", "8♠", "J♠", "K♠"]);
+    let best = Game::Omaha4.best_hand(cards(&["A♥", "A♦", "3♣", "4♣"]), table);
+    assert_eq!(best.cards, cards(&["A♥", "A♦"]));
+    assert_eq!(best.kickers, cards(&["K♠", "J♠", "8♠"]));
+
+    // the five cards make the same combination as all the seven
+    let rng = &mut StdRng::seed_from_u64(0);
+    for _ in 0..10000 {
+        let hand = CardSet::all().draw(7, rng);
+        let best = hand.best_hand();
+        assert_eq!(best.all().count_cards(), 5);
+        assert_eq!(best.all() & hand, best.all());
+        assert!(best.all().comb() == hand.comb());
+    }
+}
+
+#[test]
+fn description() {
+    assert_eq!(
+        comb(&["K♠", "K♥", "7♦", "7♣", "A♥", "2♠", "3♠"]).description(),
+        "Two pair, Kings and Sevens, Ace kicker"
+    );
+    assert_eq!(
+        comb(&["6♠", "7♥", "8♦", "9♣", "10♠", "2♥"]).description(),
+        "Straight, Ten high"
+    );
+    assert_eq!(
+        comb(&["A♠", "2♥", "3♦", "4♣", "5♠"]).description(),
+        "Straight, Five high"
+    );
+    assert_eq!(
+        short(&["A♠", "6♥", "7♦", "8♣", "9♠"]).description(),
+        "Straight, Nine high"
+    );
+    assert_eq!(
+        comb(&["10♠", "J♠", "Q♠", "K♠", "A♠"]).description(),
+        "Royal flush"
+    );
+    assert_eq!(
+        comb(&["5♠", "6♠", "7♠", "8♠", "9♠"]).description(),
+        "Straight flush, Nine high"
+    );
+    assert_eq!(
+        comb(&["Q♠", "Q♥", "Q♦", "Q♣", "9♠"]).description(),
+        "Four of a kind, Queens, Nine kicker"
+    );
+    assert_eq!(
+        comb(&["6♠", "6♥", "6♦", "A♣", "A♠"]).description(),
+        "Full house, Sixes full of Aces"
+    );
+    assert_eq!(
+        comb(&["2♠", "5♠", "8♠", "J♠", "K♠"]).description(),
+        "Flush, King-Jack-Eight-Five-Two"
+    );
+    assert_eq!(
+        comb(&["J♠", "J♥", "J♦", "4♣", "3♠"]).description(),
+        "Three of a kind, Jacks, Four-Three kickers"
+    );
+    assert_eq!(
+        comb(&["2♠", "2♥", "A♦", "9♣", "3♠"]).description(),
+        "Pair of Twos, Ace-Nine-Three kickers"
+    );
+    assert_eq!(
+        comb(&["A♠", "K♥", "9♦", "7♣", "3♠"]).description(),
+        "High card, Ace, King-Nine-Seven-Three kickers"
+    );
+    // fewer than five cards
+    assert_eq!(
+        comb(&["K♠", "K♥", "2♦", "2♣"]).description(),
+        "Two pair, Kings and Twos"
+    );
+    assert_eq!(
+        comb(&["2♠", "2♥", "2♦", "2♣"]).description(),
+        "Four of a kind, Twos"
+    );
+    assert_eq!(
+        comb(&["A♠", "A♥", "A♦", "A♣"]).description(),
+        "Four of a kind, Aces"
+    );
+    assert_eq!(
+        comb(&["K♠", "K♥", "7♦", "7♣"]).description(),
+        "Two pair, Kings and Sevens"
+    );
+    assert_eq!(
+        comb(&["K♠", "K♥", "7♦", "7♣", "2♠"]).description(),
+        "Two pair, Kings and Sevens, Two kicker"
+    );
+    assert_eq!(comb(&["A♠", "A♥"]).description(), "Pair of Aces");
+    assert_eq!(comb(&[]).description(), "No cards");
+}
+
+#[test]
+fn combination_ranks() {
+    assert_eq!(
+        comb(&["K♠", "K♥", "7♦", "7♣", "A♥", "2♠", "3♠"]).ranks(),
+        CombinationRanks::TwoPairs {
+            high: 11,
+            low: 5,
+            kicker: Some(12)
+        }
+    );
+    assert_eq!(
+        comb(&["K♠", "K♥", "7♦", "7♣"]).ranks(),
+        CombinationRanks::TwoPairs {
+            high: 11,
+            low: 5,
+            kicker: None
+        }
+    );
+    assert_eq!(
+        comb(&["3♠", "3♥", "3♦", "3♣", "2♠"]).ranks(),
+        CombinationRanks::Poker {
+            poker: 1,
+            kicker: Some(0)
+        }
+    );
+    assert_eq!(
+        comb(&["3♠", "3♥", "3♦", "3♣"]).ranks(),
+        CombinationRanks::Poker {
+            poker: 1,
+            kicker: None
+        }
+    );
+    assert_eq!(
+        comb(&["A♠", "2♥", "3♦", "4♣", "5♠"]).ranks(),
+        CombinationRanks::Straight { high: 3 }
+    );
+    assert_eq!(
+        comb(&["10♠", "J♠", "Q♠", "K♠", "A♠"]).ranks(),
+        CombinationRanks::RoyalFlush
+    );
+    assert_eq!(
+        comb(&["6♠", "6♥", "6♦", "A♣", "A♠"]).ranks(),
+        CombinationRanks::FullHouse { tris: 4, pair: 12 }
+    );
+    assert_eq!(
+        comb(&["2♠", "2♥", "A♦", "9♣", "3♠"]).ranks(),
+        CombinationRanks::Pair {
+            pair: 0,
+            kickers: vec![12, 7, 1]
+        }
+    );
+    assert_eq!(
+        comb(&["A♠", "A♥"]).ranks(),
+        CombinationRanks::Pair {
+            pair: 12,
+            kickers: vec![]
+        }
+    );
+    let ranks = CombinationRanks::Tris {
+        tris: 9,
+        kickers: vec![2, 1],
+    };
+    assert!(ranks.combination() == Some(comb(&["J♠", "J♥", "J♦", "4♣", "3♠"])));
+    assert_eq!(ranks.combination().unwrap().ranks(), ranks);
+
+    // no hand has these ranks
+    let invalid = [
+        CombinationRanks::Straight { high: 1 },
+        CombinationRanks::StraightFlush { high: 12 },
+        CombinationRanks::Flush { ranks: vec![] },
+        CombinationRanks::Flush {
+            ranks: vec![9, 8, 7, 6, 5],
+        },
+        CombinationRanks::HighCard {
+            ranks: vec![12, 3, 2, 1, 0],
+        },
+        CombinationRanks::HighCard { ranks: vec![5, 7] },
+        CombinationRanks::Pair {
+            pair: 5,
+            kickers: vec![5, 2],
+        },
+        CombinationRanks::Pair {
+            pair: 5,
+            kickers: vec![4, 3, 2, 1],
+        },
+        CombinationRanks::Tris {
+            tris: 13,
+            kickers: vec![],
+        },
+        CombinationRanks::TwoPairs {
+            high: 5,
+            low: 5,
+            kicker: None,
+        },
+        CombinationRanks::TwoPairs {
+            high: 3,
+            low: 5,
+            kicker: None,
+        },
+        CombinationRanks::TwoPairs {
+            high: 5,
+            low: 3,
+            kicker: Some(3),
+        },
+        CombinationRanks::FullHouse { tris: 4, pair: 4 },
+        CombinationRanks::Poker {
+            poker: 4,
+            kicker: Some(4),
+        },
+    ];
+    for ranks in &invalid {
+        assert!(ranks.combination().is_none(), "{:?}", ranks);
+    }
+
+    let rng = &mut StdRng::seed_from_u64(0);
+    for count in 0..=7 {
+        for _ in 0..2000 {
+            let comb = CardSet::all().draw(count, rng).comb();
+            let ranks = comb.ranks();
+            assert_eq!(ranks.category(), comb.category());
+            assert!(ranks.combination() == Some(comb));
+        }
+    }
+}
+
+#[test]
+fn class_index() {
+    assert_eq!(
+        comb(&["10♠", "J♠", "Q♠", "K♠", "A♠"]).class_index(),
+        Some(1)
+    );
+    assert_eq!(
+        comb(&["A♠", "2♠", "3♠", "4♠", "5♠"]).class_index(),
+        Some(10)
+    );
+    assert_eq!(
+        comb(&["A♠", "A♥", "A♦", "A♣", "K♠"]).class_index(),
+        Some(11)
+    );
+    assert_eq!(
+        comb(&["7♠", "5♥", "4♦", "3♣", "2♠"]).class_index(),
+        Some(7462)
+    );
+    assert_eq!(comb(&["A♠", "A♥"]).class_index(), None);
+    assert!(Combination::from_class_index(0).is_none());
+    assert!(Combination::from_class_index(7463).is_none());
+    assert_eq!(
+        Combination::from_class_index(1600).unwrap().description(),
+        "Straight, Ace high"
+    );
+
+    // first class of each category, from the royal flush down
+    let first_classes = [1, 2, 11, 167, 323, 1600, 1610, 2468, 3326, 6186];
+    let mut hands = vec![0u32; Combination::CLASSES as usize + 1];
+    for cards in CardSet::all().subsets(5) {
+        let comb = cards.comb();
+        let index = comb.class_index().unwrap();
+        assert!(Combination::from_class_index(index).unwrap() == comb);
+        let category = 9 - comb.category().index();
+        assert!(index >= first_classes[category]);
+        assert!(category == 9 || index < first_classes[category + 1]);
+        hands[index as usize] += 1;
+    }
+    assert!(hands[1..].iter().all(|&count| count > 0));
+    // a straight flush, then 4 suits of quads with a kicker, then 24 full houses
+    assert_eq!(&hands[1..4], &[4, 4, 4]);
+    assert_eq!(hands[11], 4);
+    assert_eq!(hands[167], 24);
+    assert_eq!(hands[7462], 1020);
+}
+
+/// Number of hands of `count` cards of each category, from the high card up
+fn category_counts(count: u32) -> [u64; 10] {
+    use rayon::prelude::*;
+
+    // split by the lowest card to count in parallel
+    (0..52u32)
+        .into_par_iter()
+        .map(|lowest| {
+            let higher = (lowest + 1..52).fold(CardSet::none(), |cards, i| cards | CardSet::one(i));
+            let mut counts = [0; 10];
+            for cards in higher.subsets(count - 1) {
+                counts[(cards | CardSet::one(lowest)).comb().category().index()] += 1;
+            }
+            counts
+        })
+        .reduce(
+            || [0; 10],
+            |a, b| {
+                let mut sum = a;
+                sum.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+                sum
+            },
+        )
+}
+
+#[test]
+fn five_card_frequencies() {
+    assert_eq!(
+        category_counts(5),
+        [1302540, 1098240, 123552, 54912, 10200, 5108, 3744, 624, 36, 4]
+    );
+}
+
+/// The categories of all 7-card hands match their published frequencies
+#[test]
+#[ignore]
+fn seven_card_frequencies() {
+    assert_eq!(
+        category_counts(7),
+        [23294460, 58627800, 31433400, 6461620, 6180020, 4047644, 3473184, 224848, 37260, 4324]
+    );
+}